@@ -12,10 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crossterm::event::KeyCode;
+
+use crate::core::game_state::GameSpeed;
+use crate::core::interface_state::{Modal, Tool};
+use crate::core::map::TilePosition;
+use crate::core::settings::{KeyBinding, Settings};
+use crate::core::tower::{TargetingMode, TowerKind};
 use crate::core::widget_id::WidgetId;
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub enum GameAction {}
+pub enum GameAction {
+    SetSpeed(GameSpeed),
+
+    /// Spends gold to build a tower on an empty tile.
+    BuildTower(TowerKind, TilePosition),
+
+    /// Spends gold to purchase an upgrade for the tower at a position,
+    /// identified by its index in the tower's upgrade tree.
+    UpgradeTower(TilePosition, usize),
+
+    /// Removes the tower at a position, refunding part of its cost.
+    SellTower(TilePosition),
+
+    /// Changes how the tower at a position chooses its target.
+    SetTargeting(TilePosition, TargetingMode),
+
+    /// Starts the next wave immediately in exchange for bonus gold.
+    SendWaveEarly,
+}
 
 impl From<GameAction> for InterfaceAction {
     fn from(value: GameAction) -> Self {
@@ -28,4 +53,37 @@ pub enum InterfaceAction {
     GameAction(GameAction),
     SetHover(Option<WidgetId>),
     SetMouseDown(Option<WidgetId>),
+
+    /// Sets the index of the first visible row of a scrollable widget.
+    SetScrollOffset(WidgetId, usize),
+
+    /// Changes the tower whose details are displayed, if any.
+    SelectTower(Option<TilePosition>),
+
+    /// Changes the effect of clicking on the battlefield.
+    SetTool(Tool),
+
+    /// Opens a new modal window on top of any existing ones.
+    ShowModal(Modal),
+
+    /// Closes the topmost modal window.
+    CloseModal,
+
+    /// Discards the current game (if any) and starts a new one.
+    NewGame,
+
+    /// Discards the current game and returns to the main menu.
+    QuitToMenu,
+
+    /// Replaces the user's settings and saves them to disk.
+    UpdateSettings(Settings),
+
+    /// Assigns a new key to a [KeyBinding] and closes the topmost modal.
+    RebindKey(KeyBinding, KeyCode),
+
+    /// Shows or hides the layout debugging overlay.
+    ToggleDebugLayout,
+
+    /// Exits the program.
+    Exit,
 }
@@ -12,8 +12,61 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use ratatui::buffer::Buffer;
 use ratatui::prelude::Color;
 
+use crate::core::settings::{ColorDepth, Settings, Theme};
+
+/// Controls how the colors returned by this module are displayed.
+///
+/// Widgets always draw using the standard true colors. The palette is then
+/// applied to the finished frame, see [Palette::apply_to_buffer].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct Palette {
+    pub theme: Theme,
+    pub color_depth: ColorDepth,
+}
+
+impl Palette {
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self { theme: settings.theme, color_depth: settings.color_depth }
+    }
+
+    /// Converts a color returned by this module for display. Colors which
+    /// are not RGB are returned unchanged.
+    pub fn apply(self, color: Color) -> Color {
+        let Color::Rgb(r, g, b) = color else {
+            return color;
+        };
+
+        let (r, g, b) = match self.theme {
+            Theme::Standard => (r, g, b),
+            Theme::Grayscale => {
+                let luma = (0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32).round() as u8;
+                (luma, luma, luma)
+            }
+        };
+
+        match self.color_depth {
+            ColorDepth::TrueColor => Color::Rgb(r, g, b),
+            ColorDepth::Ansi256 => Color::Indexed(ansi_256(r, g, b)),
+            ColorDepth::Ansi16 => ansi_16(r, g, b),
+        }
+    }
+
+    /// Converts the colors of every cell in `buf`.
+    pub fn apply_to_buffer(self, buf: &mut Buffer) {
+        if self == Self::default() {
+            return;
+        }
+        for cell in &mut buf.content {
+            cell.fg = self.apply(cell.fg);
+            cell.bg = self.apply(cell.bg);
+            cell.underline_color = self.apply(cell.underline_color);
+        }
+    }
+}
+
 pub fn selected() -> Color {
     dark_gray()
 }
@@ -83,5 +136,50 @@ pub fn white() -> Color {
 }
 
 fn color(s: &'static str) -> Color {
-    s.parse().expect("Invalid color literal")
+    let Ok(color @ Color::Rgb(..)) = s.parse() else {
+        panic!("Invalid color literal {s}");
+    };
+    color
+}
+
+/// Returns the closest color in the 6x6x6 color cube of the xterm 256-color
+/// palette.
+fn ansi_256(r: u8, g: u8, b: u8) -> u8 {
+    /// Value of each channel at each level of the cube, which are not evenly
+    /// spaced.
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    let level =
+        |c: u8| (0..LEVELS.len()).min_by_key(|&i| LEVELS[i].abs_diff(c)).unwrap_or_default() as u8;
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+/// Returns the closest of the 16 standard ANSI colors, using the xterm default
+/// values for each.
+fn ansi_16(r: u8, g: u8, b: u8) -> Color {
+    const COLORS: [(Color, (u8, u8, u8)); 16] = [
+        (Color::Black, (0, 0, 0)),
+        (Color::Red, (205, 0, 0)),
+        (Color::Green, (0, 205, 0)),
+        (Color::Yellow, (205, 205, 0)),
+        (Color::Blue, (0, 0, 238)),
+        (Color::Magenta, (205, 0, 205)),
+        (Color::Cyan, (0, 205, 205)),
+        (Color::Gray, (229, 229, 229)),
+        (Color::DarkGray, (127, 127, 127)),
+        (Color::LightRed, (255, 0, 0)),
+        (Color::LightGreen, (0, 255, 0)),
+        (Color::LightYellow, (255, 255, 0)),
+        (Color::LightBlue, (92, 92, 255)),
+        (Color::LightMagenta, (255, 0, 255)),
+        (Color::LightCyan, (0, 255, 255)),
+        (Color::White, (255, 255, 255)),
+    ];
+
+    let distance = |(cr, cg, cb): (u8, u8, u8)| {
+        (r as i32 - cr as i32).pow(2)
+            + (g as i32 - cg as i32).pow(2)
+            + (b as i32 - cb as i32).pow(2)
+    };
+    COLORS.iter().min_by_key(|(_, rgb)| distance(*rgb)).map(|(c, _)| *c).unwrap_or(Color::Reset)
 }
@@ -0,0 +1,296 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use derive_more::Display;
+use enum_iterator::Sequence;
+
+use crate::core::map::{Map, TilePosition};
+
+/// Uniquely identifies an enemy within a game.
+#[derive(Debug, Display, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct EnemyId(pub u32);
+
+/// Types of enemy which can attack.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Sequence)]
+pub enum EnemyKind {
+    Grunt,
+    Runner,
+    Brute,
+    Bat,
+    Warlord,
+}
+
+impl EnemyKind {
+    pub fn definition(self) -> &'static EnemyDefinition {
+        match self {
+            EnemyKind::Grunt => &GRUNT,
+            EnemyKind::Runner => &RUNNER,
+            EnemyKind::Brute => &BRUTE,
+            EnemyKind::Bat => &BAT,
+            EnemyKind::Warlord => &WARLORD,
+        }
+    }
+}
+
+/// Fixed properties shared by all enemies of an [EnemyKind].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EnemyDefinition {
+    pub kind: EnemyKind,
+    pub name: &'static str,
+
+    /// Symbol used to represent the enemy outside of the battlefield.
+    pub glyph: &'static str,
+    pub health: u32,
+
+    /// Gold awarded for defeating the enemy.
+    pub bounty: u32,
+
+    /// Distance moved along the path each tick, in
+    /// [PATH_UNITS_PER_TILE](crate::core::map::PATH_UNITS_PER_TILE) per tile.
+    pub speed: u32,
+    pub traits: &'static [EnemyTrait],
+
+    /// Scripted behaviors, used by bosses. Abilities triggered by
+    /// [AbilityTrigger::HealthBelow] must be listed from highest threshold
+    /// to lowest, and each one reached starts a new phase of the fight.
+    pub abilities: &'static [Ability],
+}
+
+/// Amount by which [EnemyTrait::Armored] reduces the damage of each hit.
+pub const ARMOR: u32 = 3;
+
+/// Special property of an enemy which players may need to plan around.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum EnemyTrait {
+    /// Flies straight across the map rather than following the path, see
+    /// [Map::flight_position].
+    Flying,
+    /// Takes [ARMOR] less damage from each hit, but always at least 1.
+    Armored,
+    /// Much stronger than other enemies, with special abilities.
+    Boss,
+}
+
+/// Effect which an enemy applies when its trigger condition is met.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Ability {
+    pub trigger: AbilityTrigger,
+    pub effect: AbilityEffect,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AbilityTrigger {
+    /// Once, when the enemy's health first falls to or below this percentage
+    /// of its maximum.
+    HealthBelow(u32),
+
+    /// Repeatedly, each time the enemy has been on the map for a multiple of
+    /// this many ticks.
+    Every(u64),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AbilityEffect {
+    /// Creates enemies at the same point on the path.
+    SpawnMinions { kind: EnemyKind, count: u32 },
+
+    /// Prevents towers within `range` tiles from attacking for `ticks`.
+    DisableTowers { range: u32, ticks: u64 },
+
+    /// Restores health to other enemies within `range` tiles.
+    HealAllies { range: u32, amount: u32 },
+
+    /// Permanently increases the enemy's speed.
+    SpeedUp(u32),
+}
+
+/// Problem with the abilities of an [EnemyDefinition], identifying the
+/// ability by its index.
+#[derive(Debug, Display, Copy, Clone, Eq, PartialEq)]
+pub enum AbilityError {
+    #[display(fmt = "Ability {_0} repeats every 0 ticks")]
+    ZeroInterval(usize),
+    #[display(fmt = "Ability {_0} has a health threshold above 100%")]
+    ThresholdAbove100(usize),
+    #[display(fmt = "Ability {_0} has a health threshold which is not below the previous one")]
+    ThresholdNotDescending(usize),
+}
+
+impl std::error::Error for AbilityError {}
+
+/// Checks that abilities can be triggered as documented on
+/// [EnemyDefinition::abilities].
+pub fn validate_abilities(abilities: &[Ability]) -> Result<(), AbilityError> {
+    let mut previous = None;
+    for (index, ability) in abilities.iter().enumerate() {
+        match ability.trigger {
+            AbilityTrigger::Every(0) => return Err(AbilityError::ZeroInterval(index)),
+            AbilityTrigger::Every(_) => {}
+            AbilityTrigger::HealthBelow(percent) if percent > 100 => {
+                return Err(AbilityError::ThresholdAbove100(index));
+            }
+            AbilityTrigger::HealthBelow(percent) => {
+                if previous.is_some_and(|p| percent >= p) {
+                    return Err(AbilityError::ThresholdNotDescending(index));
+                }
+                previous = Some(percent);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks the abilities of every [EnemyDefinition], returning the first
+/// problem found.
+pub fn validate_definitions() -> Result<(), (EnemyKind, AbilityError)> {
+    for kind in enum_iterator::all::<EnemyKind>() {
+        validate_abilities(kind.definition().abilities).map_err(|e| (kind, e))?;
+    }
+    Ok(())
+}
+
+const GRUNT: EnemyDefinition = EnemyDefinition {
+    kind: EnemyKind::Grunt,
+    name: "Grunt",
+    glyph: "g",
+    health: 10,
+    bounty: 3,
+    speed: 5,
+    traits: &[],
+    abilities: &[],
+};
+
+const RUNNER: EnemyDefinition = EnemyDefinition {
+    kind: EnemyKind::Runner,
+    name: "Runner",
+    glyph: "r",
+    health: 6,
+    bounty: 3,
+    speed: 10,
+    traits: &[],
+    abilities: &[],
+};
+
+const BRUTE: EnemyDefinition = EnemyDefinition {
+    kind: EnemyKind::Brute,
+    name: "Brute",
+    glyph: "B",
+    health: 50,
+    bounty: 10,
+    speed: 3,
+    traits: &[EnemyTrait::Armored],
+    abilities: &[],
+};
+
+const BAT: EnemyDefinition = EnemyDefinition {
+    kind: EnemyKind::Bat,
+    name: "Bat",
+    glyph: "v",
+    health: 8,
+    bounty: 4,
+    speed: 7,
+    traits: &[EnemyTrait::Flying],
+    abilities: &[],
+};
+
+const WARLORD: EnemyDefinition = EnemyDefinition {
+    kind: EnemyKind::Warlord,
+    name: "Warlord",
+    glyph: "W",
+    health: 400,
+    bounty: 100,
+    speed: 2,
+    traits: &[EnemyTrait::Boss],
+    abilities: &[
+        Ability {
+            trigger: AbilityTrigger::Every(200),
+            effect: AbilityEffect::SpawnMinions { kind: EnemyKind::Grunt, count: 3 },
+        },
+        Ability {
+            trigger: AbilityTrigger::HealthBelow(75),
+            effect: AbilityEffect::DisableTowers { range: 4, ticks: 100 },
+        },
+        Ability {
+            trigger: AbilityTrigger::HealthBelow(50),
+            effect: AbilityEffect::HealAllies { range: 5, amount: 20 },
+        },
+        Ability { trigger: AbilityTrigger::HealthBelow(25), effect: AbilityEffect::SpeedUp(3) },
+    ],
+};
+
+/// An enemy moving along the map's path.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Enemy {
+    pub id: EnemyId,
+    pub kind: EnemyKind,
+    pub health: u32,
+    pub max_health: u32,
+
+    /// Distance travelled along the map's path, in
+    /// [PATH_UNITS_PER_TILE](crate::core::map::PATH_UNITS_PER_TILE) per tile.
+    pub distance: u32,
+
+    /// Current speed, see [EnemyDefinition::speed].
+    pub speed: u32,
+
+    /// Number of ticks this enemy has been on the map.
+    pub age: u64,
+
+    /// Number of [AbilityTrigger::HealthBelow] abilities which have been
+    /// triggered.
+    pub phase: usize,
+}
+
+impl Enemy {
+    /// Creates an enemy with full health at `distance` along the path.
+    pub fn new(id: EnemyId, kind: EnemyKind, distance: u32) -> Self {
+        let definition = kind.definition();
+        Self {
+            id,
+            kind,
+            health: definition.health,
+            max_health: definition.health,
+            distance,
+            speed: definition.speed,
+            age: 0,
+            phase: 0,
+        }
+    }
+
+    pub fn definition(&self) -> &'static EnemyDefinition {
+        self.kind.definition()
+    }
+
+    pub fn has_trait(&self, enemy_trait: EnemyTrait) -> bool {
+        self.definition().traits.contains(&enemy_trait)
+    }
+
+    pub fn is_boss(&self) -> bool {
+        self.has_trait(EnemyTrait::Boss)
+    }
+
+    /// Tile this enemy is currently over, or `None` if it has left the map.
+    pub fn position(&self, map: &Map) -> Option<TilePosition> {
+        map.enemy_position(self.distance, self.has_trait(EnemyTrait::Flying))
+    }
+
+    /// Fraction of this enemy's maximum health remaining, from 0.0 to 1.0.
+    pub fn health_fraction(&self) -> f64 {
+        if self.max_health == 0 {
+            0.0
+        } else {
+            self.health as f64 / self.max_health as f64
+        }
+    }
+}
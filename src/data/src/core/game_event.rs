@@ -0,0 +1,99 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::core::enemy::EnemyKind;
+use crate::core::map::TilePosition;
+use crate::core::tower::TowerKind;
+
+/// Something which happened during a simulation tick that the interface may
+/// want to show the player.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GameEvent {
+    /// The wave with the given number, starting from 1, began.
+    WaveStarted {
+        wave: u32,
+    },
+
+    /// The next wave was started early, awarding `bonus` gold.
+    WaveSentEarly {
+        bonus: u32,
+    },
+
+    TowerBuilt {
+        kind: TowerKind,
+        position: TilePosition,
+    },
+
+    /// A tower was sold, refunding `refund` gold.
+    TowerSold {
+        kind: TowerKind,
+        position: TilePosition,
+        refund: u32,
+    },
+
+    /// A tower hit the enemy at `position`, including with the blow which
+    /// defeated it.
+    EnemyDamaged {
+        position: TilePosition,
+        amount: u32,
+    },
+
+    /// An enemy was defeated at `position`, awarding `bounty` gold.
+    EnemyDefeated {
+        kind: EnemyKind,
+        position: TilePosition,
+        bounty: u32,
+    },
+
+    /// An enemy reached the end of the path, costing a life.
+    EnemyEscaped {
+        kind: EnemyKind,
+    },
+}
+
+/// Events which have not yet been displayed, in the order they happened.
+///
+/// Each event has a sequence number counting from the start of the game, so
+/// that an observer which sees the same events more than once can tell
+/// which ones are new.
+#[derive(Debug, Clone, Default)]
+pub struct GameEvents {
+    events: Vec<GameEvent>,
+
+    /// Number of events cleared before `events[0]`.
+    cleared: u64,
+}
+
+impl GameEvents {
+    pub fn push(&mut self, event: GameEvent) {
+        self.events.push(event);
+    }
+
+    /// Events which have not been cleared, with their sequence numbers.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &GameEvent)> {
+        (self.cleared..).zip(&self.events)
+    }
+
+    /// Sequence number which the next event will have.
+    pub fn next_sequence(&self) -> u64 {
+        self.cleared + self.events.len() as u64
+    }
+
+    /// Discards all events. Sequence numbers continue from where they left
+    /// off.
+    pub fn clear(&mut self) {
+        self.cleared = self.next_sequence();
+        self.events.clear();
+    }
+}
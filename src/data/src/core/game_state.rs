@@ -12,5 +12,114 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::core::enemy::{Enemy, EnemyId};
+use crate::core::game_event::GameEvents;
+use crate::core::map::Map;
+use crate::core::projectile::{Effect, Projectile};
+use crate::core::tower::{Tower, TowerKind};
+use crate::core::wave::{PendingSpawn, Wave};
+
+/// Amount of real time between simulation ticks at normal game speed.
+pub const TICK_DURATION: Duration = Duration::from_millis(50);
+
 #[derive(Debug, Clone)]
-pub struct GameState {}
+pub struct GameState {
+    /// Number of simulation ticks which have elapsed in this game.
+    pub tick: u64,
+
+    /// Rate at which the simulation runs relative to real time.
+    pub speed: GameSpeed,
+
+    /// Currency available for building and upgrading towers.
+    pub gold: u32,
+
+    /// Number of enemies which can reach the end of the path before the
+    /// game is lost.
+    pub lives: u32,
+    pub score: u32,
+
+    /// Number of the wave currently in progress, starting from 1, or 0
+    /// before the first wave.
+    pub wave: u32,
+
+    /// Every wave in this game, in the order they attack.
+    pub waves: Vec<Wave>,
+
+    /// Tick on which the next wave starts, if there are any remaining.
+    pub next_wave_tick: Option<u64>,
+
+    /// Enemies from waves which have started but which have not yet
+    /// appeared, in the order they appear.
+    pub pending_spawns: Vec<PendingSpawn>,
+
+    pub map: Map,
+    pub towers: Vec<Tower>,
+
+    /// Types of tower which the player is able to build, in the order they
+    /// are listed in the shop.
+    pub unlocked_towers: Vec<TowerKind>,
+    pub enemies: Vec<Enemy>,
+
+    /// ID to assign to the next enemy which appears.
+    pub next_enemy_id: EnemyId,
+    pub projectiles: Vec<Projectile>,
+    pub effects: Vec<Effect>,
+
+    /// Things which happened during recent ticks, cleared by the interface
+    /// once they have been displayed.
+    pub events: GameEvents,
+}
+
+/// Multiplier applied to the rate at which simulation ticks occur.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Sequence, Serialize, Deserialize)]
+pub enum GameSpeed {
+    #[default]
+    Normal,
+    Fast,
+    Fastest,
+}
+
+impl GameState {
+    /// Number of waves which must be survived to win.
+    pub fn total_waves(&self) -> u32 {
+        self.waves.len() as u32
+    }
+
+    /// The boss enemy on the map which has travelled furthest, if any.
+    pub fn boss(&self) -> Option<&Enemy> {
+        self.enemies.iter().filter(|e| e.is_boss()).max_by_key(|e| e.distance)
+    }
+
+    /// Waves which have not started yet, in the order they will attack.
+    pub fn upcoming_waves(&self) -> &[Wave] {
+        self.waves.get(self.wave as usize..).unwrap_or_default()
+    }
+
+    /// Number of ticks remaining until the next wave starts.
+    pub fn ticks_until_next_wave(&self) -> Option<u64> {
+        self.next_wave_tick.map(|t| t.saturating_sub(self.tick))
+    }
+
+    /// Real time remaining until the next wave starts at the current game
+    /// speed.
+    pub fn time_until_next_wave(&self) -> Option<Duration> {
+        let multiplier = self.speed.multiplier() as f64;
+        self.ticks_until_next_wave().map(|ticks| TICK_DURATION.mul_f64(ticks as f64 / multiplier))
+    }
+}
+
+impl GameSpeed {
+    /// Number of simulation ticks to run for each tick at normal speed.
+    pub fn multiplier(self) -> u32 {
+        match self {
+            GameSpeed::Normal => 1,
+            GameSpeed::Fast => 2,
+            GameSpeed::Fastest => 4,
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::core::map::TilePosition;
+use crate::core::settings::KeyBinding;
+use crate::core::tower::TowerKind;
+
+/// State of the user interface which is not part of the game simulation.
+#[derive(Debug, Clone, Default)]
+pub struct InterfaceState {
+    pub screen: Screen,
+
+    /// Stack of modal windows currently being displayed. The last entry is
+    /// topmost and is the only one which receives input.
+    pub modals: Vec<Modal>,
+
+    /// Effect of clicking on the battlefield.
+    pub tool: Tool,
+
+    /// Position of the tower whose details are displayed, if any.
+    pub selected_tower: Option<TilePosition>,
+
+    /// If true, outlines of all laid-out areas are drawn on top of the
+    /// interface.
+    pub debug_layout: bool,
+}
+
+impl InterfaceState {
+    /// Returns the topmost open modal window, if any.
+    pub fn current_modal(&self) -> Option<Modal> {
+        self.modals.last().copied()
+    }
+
+    /// True if the game simulation should currently be frozen.
+    pub fn is_paused(&self) -> bool {
+        self.screen == Screen::Game && !self.modals.is_empty()
+    }
+}
+
+/// Top-level screens of the game.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum Screen {
+    #[default]
+    MainMenu,
+    Game,
+}
+
+/// Ways of interacting with the battlefield.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum Tool {
+    /// Clicking selects things on the map.
+    #[default]
+    Select,
+
+    /// Clicking places a new tower of the given kind.
+    Build(TowerKind),
+}
+
+/// Windows which display on top of the current [Screen] and block
+/// interaction with it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Modal {
+    PauseMenu,
+    Settings,
+
+    /// Waiting for the user to press a new key for a [KeyBinding].
+    RebindKey(KeyBinding),
+    Confirm(Confirmation),
+}
+
+/// Actions which require the user to confirm their choice before being
+/// performed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Confirmation {
+    QuitToMenu,
+    QuitGame,
+
+    /// Selling the tower at the given position.
+    SellTower(TilePosition),
+}
@@ -0,0 +1,135 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Width of the map in tiles.
+pub const MAP_WIDTH: u16 = 60;
+
+/// Height of the map in tiles.
+pub const MAP_HEIGHT: u16 = 22;
+
+/// Number of units of distance along the path per tile.
+pub const PATH_UNITS_PER_TILE: u32 = 100;
+
+/// Location of a tile on the map, with (0, 0) at the top left.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TilePosition {
+    pub x: u16,
+    pub y: u16,
+}
+
+impl TilePosition {
+    pub const fn new(x: u16, y: u16) -> Self {
+        Self { x, y }
+    }
+}
+
+/// Type of ground on a map tile.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum Terrain {
+    #[default]
+    Grass,
+    Forest,
+    Water,
+    Rock,
+}
+
+/// Static layout of the battlefield: the terrain of each tile and the path
+/// enemies follow.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Map {
+    terrain: Vec<Terrain>,
+
+    /// Tiles enemies walk through, in order from where they enter the map
+    /// to where they leave it. Each tile is adjacent to the next.
+    pub path: Vec<TilePosition>,
+
+    /// Distances along the path at which enemies enter the map, indexed by
+    /// [crate::core::wave::WaveGroup::spawn_point].
+    pub spawn_points: Vec<u32>,
+}
+
+impl Map {
+    /// Creates a map covered in grass with no path.
+    pub fn new() -> Self {
+        Self {
+            terrain: vec![Terrain::Grass; MAP_WIDTH as usize * MAP_HEIGHT as usize],
+            path: vec![],
+            spawn_points: vec![0],
+        }
+    }
+
+    pub fn terrain(&self, position: TilePosition) -> Terrain {
+        self.terrain[Self::index(position)]
+    }
+
+    pub fn set_terrain(&mut self, position: TilePosition, terrain: Terrain) {
+        self.terrain[Self::index(position)] = terrain;
+    }
+
+    pub fn is_path(&self, position: TilePosition) -> bool {
+        self.path.contains(&position)
+    }
+
+    /// Distance along the path at which enemies from the given spawn point
+    /// enter the map, or the start of the path if there is no such spawn
+    /// point.
+    pub fn spawn_distance(&self, spawn_point: usize) -> u32 {
+        self.spawn_points.get(spawn_point).copied().unwrap_or_default()
+    }
+
+    /// Tile reached after travelling `distance` units along the path, or
+    /// `None` if this is beyond the end of the path.
+    pub fn path_position(&self, distance: u32) -> Option<TilePosition> {
+        self.path.get((distance / PATH_UNITS_PER_TILE) as usize).copied()
+    }
+
+    /// Tile reached after flying `distance` units in a straight line from the
+    /// first tile of the path to the last, moving one tile horizontally,
+    /// vertically or diagonally per [PATH_UNITS_PER_TILE]. Returns `None` if
+    /// this is beyond the end of the path.
+    pub fn flight_position(&self, distance: u32) -> Option<TilePosition> {
+        let (&start, &end) = (self.path.first()?, self.path.last()?);
+        let steps = start.x.abs_diff(end.x).max(start.y.abs_diff(end.y)) as u32;
+        let step = distance / PATH_UNITS_PER_TILE;
+        if step > steps {
+            return None;
+        }
+        let fraction = if steps == 0 { 0.0 } else { step as f64 / steps as f64 };
+        let lerp =
+            |from: u16, to: u16| (from as f64 + (to as f64 - from as f64) * fraction).round();
+        Some(TilePosition::new(lerp(start.x, end.x) as u16, lerp(start.y, end.y) as u16))
+    }
+
+    /// Tile reached by an enemy after travelling `distance` units, via
+    /// [Self::flight_position] if it is `flying` or [Self::path_position]
+    /// otherwise.
+    pub fn enemy_position(&self, distance: u32, flying: bool) -> Option<TilePosition> {
+        if flying {
+            self.flight_position(distance)
+        } else {
+            self.path_position(distance)
+        }
+    }
+
+    fn index(position: TilePosition) -> usize {
+        assert!(position.x < MAP_WIDTH && position.y < MAP_HEIGHT, "{position:?} is off the map");
+        position.y as usize * MAP_WIDTH as usize + position.x as usize
+    }
+}
+
+impl Default for Map {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -14,5 +14,14 @@
 
 pub mod actions;
 pub mod colors;
+pub mod enemy;
+pub mod game_event;
 pub mod game_state;
+pub mod interface_state;
+pub mod map;
+pub mod projectile;
+pub mod settings;
+pub mod tower;
+pub mod upgrades;
+pub mod wave;
 pub mod widget_id;
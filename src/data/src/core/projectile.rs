@@ -0,0 +1,50 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::core::map::TilePosition;
+use crate::core::tower::TowerKind;
+
+/// Shot fired by a tower during the current tick, positioned midway between
+/// the tower and its target.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Projectile {
+    /// Type of tower which fired this projectile.
+    pub source: TowerKind,
+    pub position: TilePosition,
+}
+
+/// Lingering result of an attack which occupies a tile for a number of
+/// ticks.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Effect {
+    pub kind: EffectKind,
+    pub position: TilePosition,
+    pub remaining_ticks: u32,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EffectKind {
+    Explosion,
+    Frost,
+}
+
+impl EffectKind {
+    /// Number of ticks the effect lingers for after it is created.
+    pub fn duration(self) -> u32 {
+        match self {
+            EffectKind::Explosion => 6,
+            EffectKind::Frost => 10,
+        }
+    }
+}
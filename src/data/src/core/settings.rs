@@ -0,0 +1,152 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::core::game_state::GameSpeed;
+
+/// User preferences which persist between runs of the game.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Speed at which new games start.
+    pub game_speed: GameSpeed,
+    pub theme: Theme,
+    pub color_depth: ColorDepth,
+
+    /// Whether the game should receive mouse events from the terminal.
+    pub mouse_capture: bool,
+
+    /// Whether to display the attack range of towers on the battlefield.
+    pub show_range_circles: bool,
+
+    /// Whether to display health bars above damaged enemies.
+    pub show_health_bars: bool,
+
+    /// Whether to display floating numbers when enemies take damage or gold
+    /// is earned.
+    pub show_damage_numbers: bool,
+
+    /// Whether to draw each map tile using multiple terminal cells when the
+    /// terminal is large enough.
+    pub scale_map: bool,
+
+    /// How often the game in progress is saved automatically.
+    pub autosave: AutosaveFrequency,
+    pub key_bindings: KeyBindings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            game_speed: GameSpeed::Normal,
+            theme: Theme::Standard,
+            color_depth: ColorDepth::TrueColor,
+            mouse_capture: true,
+            show_range_circles: true,
+            show_health_bars: true,
+            show_damage_numbers: true,
+            scale_map: false,
+            autosave: AutosaveFrequency::EveryWave,
+            key_bindings: KeyBindings::default(),
+        }
+    }
+}
+
+/// Transformation applied to every color in the game's palette.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Sequence, Serialize, Deserialize)]
+pub enum Theme {
+    #[default]
+    Standard,
+    Grayscale,
+}
+
+/// Number of distinct colors the user's terminal is able to display.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Sequence, Serialize, Deserialize)]
+pub enum ColorDepth {
+    /// 24-bit RGB colors
+    #[default]
+    TrueColor,
+
+    /// The xterm 256-color palette
+    Ansi256,
+
+    /// The 16 standard ANSI colors
+    Ansi16,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Sequence, Serialize, Deserialize)]
+pub enum AutosaveFrequency {
+    Never,
+    #[default]
+    EveryWave,
+    EveryFiveWaves,
+}
+
+/// Actions which can be assigned to a key by the user.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Sequence)]
+pub enum KeyBinding {
+    Pause,
+    ChangeSpeed,
+    Quit,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    pub pause: KeyCode,
+    pub change_speed: KeyCode,
+    pub quit: KeyCode,
+}
+
+impl KeyBindings {
+    pub fn get(&self, binding: KeyBinding) -> KeyCode {
+        match binding {
+            KeyBinding::Pause => self.pause,
+            KeyBinding::ChangeSpeed => self.change_speed,
+            KeyBinding::Quit => self.quit,
+        }
+    }
+
+    /// Assigns `code` to `binding`. Any other binding which used `code` is
+    /// given the key previously assigned to `binding`, so that no two
+    /// bindings share a key.
+    pub fn rebind(&mut self, binding: KeyBinding, code: KeyCode) {
+        let previous = self.get(binding);
+        if let Some(other) = enum_iterator::all::<KeyBinding>().find(|b| self.get(*b) == code) {
+            self.set(other, previous);
+        }
+        self.set(binding, code);
+    }
+
+    pub fn set(&mut self, binding: KeyBinding, code: KeyCode) {
+        match binding {
+            KeyBinding::Pause => self.pause = code,
+            KeyBinding::ChangeSpeed => self.change_speed = code,
+            KeyBinding::Quit => self.quit = code,
+        }
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            pause: KeyCode::Char('p'),
+            change_speed: KeyCode::Char('f'),
+            quit: KeyCode::Char('q'),
+        }
+    }
+}
@@ -0,0 +1,270 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use enum_iterator::Sequence;
+
+use crate::core::map::TilePosition;
+use crate::core::upgrades::{
+    StatModifiers, TowerStats, UpgradeNode, UpgradeTree, UpgradeTreeError,
+};
+
+/// Types of tower which can be built.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Sequence)]
+pub enum TowerKind {
+    Arrow,
+    Cannon,
+    Frost,
+}
+
+impl TowerKind {
+    pub fn definition(self) -> &'static TowerDefinition {
+        match self {
+            TowerKind::Arrow => &ARROW,
+            TowerKind::Cannon => &CANNON,
+            TowerKind::Frost => &FROST,
+        }
+    }
+}
+
+/// Fixed properties shared by all towers of a [TowerKind].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TowerDefinition {
+    pub kind: TowerKind,
+    pub name: &'static str,
+
+    /// Symbol used to draw the tower on the battlefield.
+    pub glyph: &'static str,
+
+    /// Amount of gold required to build the tower.
+    pub cost: u32,
+
+    /// Key which selects the tower for building.
+    pub hotkey: KeyCode,
+
+    /// Stats of a newly-built tower.
+    pub stats: TowerStats,
+
+    pub upgrades: UpgradeTree,
+}
+
+/// Rule used by a tower to pick which enemy in range to attack.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Sequence)]
+pub enum TargetingMode {
+    /// The enemy furthest along the path.
+    #[default]
+    First,
+    /// The enemy least far along the path.
+    Last,
+    /// The enemy with the most health remaining.
+    Strongest,
+    /// The enemy with the least health remaining.
+    Weakest,
+}
+
+const ARROW: TowerDefinition = TowerDefinition {
+    kind: TowerKind::Arrow,
+    name: "Arrow",
+    glyph: "A",
+    cost: 50,
+    hotkey: KeyCode::Char('1'),
+    stats: TowerStats { damage: 5, range: 4, reload: 10, targets: 1 },
+    upgrades: UpgradeTree {
+        start: &[0],
+        nodes: &[
+            UpgradeNode {
+                name: "Sharp Arrows",
+                cost: 40,
+                modifiers: StatModifiers { damage: 3, ..StatModifiers::NONE },
+                next: &[1, 2],
+            },
+            UpgradeNode {
+                name: "Multishot",
+                cost: 90,
+                modifiers: StatModifiers { damage: -2, targets: 2, ..StatModifiers::NONE },
+                next: &[3],
+            },
+            UpgradeNode {
+                name: "Sniper",
+                cost: 90,
+                modifiers: StatModifiers { damage: 8, range: 4, reload: 6, ..StatModifiers::NONE },
+                next: &[4],
+            },
+            UpgradeNode {
+                name: "Arrow Storm",
+                cost: 150,
+                modifiers: StatModifiers { reload: -3, targets: 2, ..StatModifiers::NONE },
+                next: &[],
+            },
+            UpgradeNode {
+                name: "Deadeye",
+                cost: 150,
+                modifiers: StatModifiers { damage: 15, ..StatModifiers::NONE },
+                next: &[],
+            },
+        ],
+    },
+};
+
+const CANNON: TowerDefinition = TowerDefinition {
+    kind: TowerKind::Cannon,
+    name: "Cannon",
+    glyph: "C",
+    cost: 120,
+    hotkey: KeyCode::Char('2'),
+    stats: TowerStats { damage: 20, range: 3, reload: 30, targets: 1 },
+    upgrades: UpgradeTree {
+        start: &[0],
+        nodes: &[
+            UpgradeNode {
+                name: "Heavy Shot",
+                cost: 90,
+                modifiers: StatModifiers { damage: 15, ..StatModifiers::NONE },
+                next: &[1, 2],
+            },
+            UpgradeNode {
+                name: "Long Barrel",
+                cost: 140,
+                modifiers: StatModifiers { damage: 10, range: 2, ..StatModifiers::NONE },
+                next: &[],
+            },
+            UpgradeNode {
+                name: "Autoloader",
+                cost: 140,
+                modifiers: StatModifiers { reload: -12, ..StatModifiers::NONE },
+                next: &[],
+            },
+        ],
+    },
+};
+
+const FROST: TowerDefinition = TowerDefinition {
+    kind: TowerKind::Frost,
+    name: "Frost",
+    glyph: "F",
+    cost: 90,
+    hotkey: KeyCode::Char('3'),
+    stats: TowerStats { damage: 2, range: 3, reload: 15, targets: 1 },
+    upgrades: UpgradeTree {
+        start: &[0],
+        nodes: &[
+            UpgradeNode {
+                name: "Deep Freeze",
+                cost: 60,
+                modifiers: StatModifiers { damage: 2, ..StatModifiers::NONE },
+                next: &[1, 2],
+            },
+            UpgradeNode {
+                name: "Blizzard",
+                cost: 120,
+                modifiers: StatModifiers { range: 2, targets: 3, ..StatModifiers::NONE },
+                next: &[],
+            },
+            UpgradeNode {
+                name: "Permafrost",
+                cost: 120,
+                modifiers: StatModifiers { damage: 4, reload: -5, ..StatModifiers::NONE },
+                next: &[],
+            },
+        ],
+    },
+};
+
+/// A tower which has been built on the map.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Tower {
+    pub kind: TowerKind,
+    pub position: TilePosition,
+
+    /// Indices of the nodes in the tower's [UpgradeTree] which have been
+    /// purchased, in order.
+    pub upgrades: Vec<usize>,
+
+    pub targeting: TargetingMode,
+
+    /// Total gold spent on building and upgrading this tower.
+    pub invested: u32,
+
+    /// Number of enemies defeated by this tower.
+    pub kills: u32,
+
+    /// Total damage dealt by this tower.
+    pub damage_dealt: u32,
+
+    /// Tick until which the tower cannot attack, after being disabled by an
+    /// enemy ability.
+    pub disabled_until: u64,
+
+    /// Tick on which the tower has reloaded and can attack again.
+    pub ready_at: u64,
+}
+
+impl Tower {
+    /// Creates a newly-built tower.
+    pub fn new(kind: TowerKind, position: TilePosition) -> Self {
+        Self {
+            kind,
+            position,
+            upgrades: vec![],
+            targeting: TargetingMode::default(),
+            invested: kind.definition().cost,
+            kills: 0,
+            damage_dealt: 0,
+            disabled_until: 0,
+            ready_at: 0,
+        }
+    }
+
+    pub fn definition(&self) -> &'static TowerDefinition {
+        self.kind.definition()
+    }
+
+    /// True if the tower is unable to attack on the given tick.
+    pub fn is_disabled(&self, tick: u64) -> bool {
+        tick < self.disabled_until
+    }
+
+    /// Level of this tower, starting from 1 and increasing with each
+    /// upgrade.
+    pub fn level(&self) -> usize {
+        self.upgrades.len() + 1
+    }
+
+    /// Current stats, including all purchased upgrades.
+    pub fn stats(&self) -> TowerStats {
+        let nodes = self.definition().upgrades.nodes;
+        self.upgrades
+            .iter()
+            .fold(self.definition().stats, |stats, &i| stats.apply(nodes[i].modifiers))
+    }
+
+    /// Indices of the upgrades which can be purchased next. Empty once the
+    /// tower is fully upgraded.
+    pub fn available_upgrades(&self) -> &'static [usize] {
+        let tree = &self.definition().upgrades;
+        match self.upgrades.last() {
+            Some(&last) => tree.nodes[last].next,
+            None => tree.start,
+        }
+    }
+}
+
+/// Checks the upgrade tree of every [TowerDefinition], returning the first
+/// problem found.
+pub fn validate_definitions() -> Result<(), (TowerKind, UpgradeTreeError)> {
+    for kind in enum_iterator::all::<TowerKind>() {
+        kind.definition().upgrades.validate().map_err(|e| (kind, e))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,164 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use derive_more::Display;
+
+/// Combat properties of a tower.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TowerStats {
+    /// Damage dealt by each shot.
+    pub damage: u32,
+
+    /// Maximum distance to a target, in tiles.
+    pub range: u32,
+
+    /// Number of ticks between shots.
+    pub reload: u32,
+
+    /// Number of enemies attacked by each shot.
+    pub targets: u32,
+}
+
+impl TowerStats {
+    /// Returns these stats with `modifiers` added. Reload time and targets
+    /// never drop below 1.
+    pub fn apply(self, modifiers: StatModifiers) -> Self {
+        let add = |value: u32, delta: i32| value.saturating_add_signed(delta);
+        Self {
+            damage: add(self.damage, modifiers.damage),
+            range: add(self.range, modifiers.range),
+            reload: add(self.reload, modifiers.reload).max(1),
+            targets: add(self.targets, modifiers.targets).max(1),
+        }
+    }
+}
+
+/// Changes made to [TowerStats] by an upgrade.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct StatModifiers {
+    pub damage: i32,
+    pub range: i32,
+    pub reload: i32,
+    pub targets: i32,
+}
+
+impl StatModifiers {
+    /// Modifiers which leave stats unchanged.
+    pub const NONE: Self = Self { damage: 0, range: 0, reload: 0, targets: 0 };
+}
+
+/// Improvement which can be purchased for a tower.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UpgradeNode {
+    pub name: &'static str,
+    pub cost: u32,
+    pub modifiers: StatModifiers,
+
+    /// Indices of the upgrades which become available after purchasing this
+    /// one. If there are several, buying one locks out the others.
+    pub next: &'static [usize],
+}
+
+/// Upgrades available for a type of tower.
+///
+/// Each purchase offers a choice between the branches listed in its
+/// [UpgradeNode::next], so the upgrades bought for a tower always form a
+/// single path from one of the `start` nodes.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UpgradeTree {
+    /// Indices of the upgrades available for a newly-built tower.
+    pub start: &'static [usize],
+    pub nodes: &'static [UpgradeNode],
+}
+
+/// Maximum number of upgrades a tower can offer to choose between at once.
+pub const MAX_BRANCHES: usize = 2;
+
+/// Problem found by [UpgradeTree::validate].
+#[derive(Debug, Display, Copy, Clone, Eq, PartialEq)]
+pub enum UpgradeTreeError {
+    #[display(fmt = "Upgrade {from} refers to missing upgrade {to}")]
+    MissingNode { from: usize, to: usize },
+    #[display(fmt = "A new tower starts with missing upgrade {_0}")]
+    MissingStartNode(usize),
+    #[display(fmt = "Upgrade {_0} can be reached by more than one path")]
+    MultipleParents(usize),
+    #[display(fmt = "Upgrade {_0} is part of a cycle")]
+    Cycle(usize),
+    #[display(fmt = "Upgrade {_0} can never be purchased")]
+    Unreachable(usize),
+    #[display(fmt = "More than {MAX_BRANCHES} upgrades are available for a new tower")]
+    TooManyStartingBranches,
+    #[display(fmt = "Upgrade {_0} leads to more than {MAX_BRANCHES} upgrades")]
+    TooManyBranches(usize),
+}
+
+impl std::error::Error for UpgradeTreeError {}
+
+impl UpgradeTree {
+    /// Checks that every node can be reached from `start` by exactly one
+    /// path, and that no choice offers more than [MAX_BRANCHES] upgrades.
+    pub fn validate(&self) -> Result<(), UpgradeTreeError> {
+        if self.start.len() > MAX_BRANCHES {
+            return Err(UpgradeTreeError::TooManyStartingBranches);
+        }
+        if let Some(index) = self.nodes.iter().position(|n| n.next.len() > MAX_BRANCHES) {
+            return Err(UpgradeTreeError::TooManyBranches(index));
+        }
+
+        let mut visited = vec![false; self.nodes.len()];
+        let mut stack = self.start.iter().map(|&i| (None, i)).collect::<Vec<_>>();
+        while let Some((from, index)) = stack.pop() {
+            if index >= self.nodes.len() {
+                return Err(match from {
+                    Some(from) => UpgradeTreeError::MissingNode { from, to: index },
+                    None => UpgradeTreeError::MissingStartNode(index),
+                });
+            }
+            if visited[index] {
+                // Any path which revisits a node must loop if the node leads
+                // back to itself, and is a merge of two branches otherwise.
+                return Err(if self.leads_to(index, index) {
+                    UpgradeTreeError::Cycle(index)
+                } else {
+                    UpgradeTreeError::MultipleParents(index)
+                });
+            }
+            visited[index] = true;
+            stack.extend(self.nodes[index].next.iter().map(|&next| (Some(index), next)));
+        }
+
+        match visited.iter().position(|v| !v) {
+            Some(index) => Err(UpgradeTreeError::Unreachable(index)),
+            None => Ok(()),
+        }
+    }
+
+    /// True if `target` can be reached by following one or more `next`
+    /// links from `from`.
+    fn leads_to(&self, from: usize, target: usize) -> bool {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut stack = self.nodes[from].next.to_vec();
+        while let Some(index) = stack.pop() {
+            if index == target {
+                return true;
+            }
+            if index < self.nodes.len() && !seen[index] {
+                seen[index] = true;
+                stack.extend_from_slice(self.nodes[index].next);
+            }
+        }
+        false
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::core::enemy::EnemyKind;
+
+/// Group of identical enemies within a [Wave].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WaveGroup {
+    pub enemy: EnemyKind,
+    pub count: u32,
+
+    /// Index of the spawn point at which the group enters the map.
+    pub spawn_point: usize,
+}
+
+/// Enemies which attack together when a wave starts.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Wave {
+    pub groups: Vec<WaveGroup>,
+}
+
+/// Enemy from a wave which is waiting to enter the map.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PendingSpawn {
+    pub enemy: EnemyKind,
+
+    /// Index of the spawn point at which the enemy enters the map, see
+    /// [WaveGroup::spawn_point].
+    pub spawn_point: usize,
+
+    /// Tick on which the enemy appears.
+    pub tick: u64,
+}
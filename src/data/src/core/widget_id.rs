@@ -12,5 +12,136 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-pub enum WidgetId {}
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+/// Maximum number of path segments in a [WidgetId].
+pub const MAX_DEPTH: usize = 4;
+
+/// Names for widgets, or for groups of related widgets, which are known
+/// statically.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum WidgetName {
+    MainMenu,
+    PauseMenu,
+    SettingsMenu,
+    ConfirmationDialog,
+    NewGame,
+    Settings,
+    Quit,
+    Resume,
+    Restart,
+    Back,
+    Yes,
+    No,
+    GameSpeed,
+    Theme,
+    ColorDepth,
+    MouseCapture,
+    RangeCircles,
+    DamageNumbers,
+    HealthBars,
+    ScaleMap,
+    Autosave,
+    Battlefield,
+    TowerShop,
+    TowerPanel,
+    Upgrade,
+    Sell,
+    Close,
+    Targeting,
+    Hud,
+    Gold,
+    Lives,
+    Wave,
+    Score,
+    WavePreview,
+    SendEarly,
+    KeyBinding,
+}
+
+/// Identifies a widget across frames.
+///
+/// An ID is a path of up to [MAX_DEPTH] segments, each of which is either a
+/// [WidgetName] or an index. Static widgets are identified by name, while
+/// widgets generated from data are identified by their index within a named
+/// parent, e.g. `TowerPanel/Upgrade[3]` for the button which buys a tower's
+/// fourth upgrade.
+///
+/// Children of an ID which already has [MAX_DEPTH] segments replace its last
+/// segment with a hash of that segment and the child's, so they remain
+/// distinct from each other but are not considered its descendants.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct WidgetId {
+    segments: [Segment; MAX_DEPTH],
+    len: u8,
+}
+
+impl WidgetId {
+    pub const fn new(name: WidgetName) -> Self {
+        let mut segments = [Segment::Empty; MAX_DEPTH];
+        segments[0] = Segment::Name(name);
+        Self { segments, len: 1 }
+    }
+
+    /// Returns the ID of a child of this widget with the given name.
+    pub fn child(self, name: WidgetName) -> Self {
+        self.push(Segment::Name(name))
+    }
+
+    /// Returns the ID of the child of this widget at position `index`.
+    pub fn index(self, index: usize) -> Self {
+        self.push(Segment::Index(index))
+    }
+
+    /// True if `other` is a child of this widget, or a child of one of its
+    /// children, etc.
+    pub fn is_ancestor_of(self, other: WidgetId) -> bool {
+        other.len > self.len
+            && other.segments[..self.len as usize] == self.segments[..self.len as usize]
+    }
+
+    fn push(mut self, segment: Segment) -> Self {
+        if (self.len as usize) < MAX_DEPTH {
+            self.segments[self.len as usize] = segment;
+            self.len += 1;
+        } else {
+            let mut hasher = DefaultHasher::new();
+            (self.segments[MAX_DEPTH - 1], segment).hash(&mut hasher);
+            self.segments[MAX_DEPTH - 1] = Segment::Hash(hasher.finish());
+        }
+        self
+    }
+}
+
+impl Display for WidgetId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments[..self.len as usize].iter().enumerate() {
+            match segment {
+                Segment::Name(name) if i == 0 => write!(f, "{name:?}")?,
+                Segment::Name(name) => write!(f, "/{name:?}")?,
+                Segment::Index(index) => write!(f, "[{index}]")?,
+                Segment::Hash(hash) => write!(f, "/#{hash:016x}")?,
+                Segment::Empty => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Debug for WidgetId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum Segment {
+    Empty,
+    Name(WidgetName),
+    Index(usize),
+    /// Combination of segments beyond [MAX_DEPTH].
+    Hash(u64),
+}
@@ -0,0 +1,186 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Time-based animation helpers.
+//!
+//! Widgets are redrawn from scratch every frame, so animations are expressed
+//! as pure functions of the current time via [Tween] and [Sprite]. A widget
+//! which is animating should call
+//! [RenderContext::request_redraw](crate::core::render_context::RenderContext::request_redraw)
+//! with [FRAME_INTERVAL] so that the next frame is drawn even without input.
+
+use std::time::{Duration, Instant};
+
+use ratatui::layout::Position;
+use ratatui::style::Color;
+
+/// Interval between frames while an animation is running.
+pub const FRAME_INTERVAL: Duration = Duration::from_millis(33);
+
+/// Amount of time a value is highlighted after it changes.
+pub const FLASH_DURATION: Duration = Duration::from_millis(600);
+
+/// Curve mapping linear progress through an animation to the fraction of
+/// the change which has been applied.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    /// Starts slowly and accelerates.
+    EaseIn,
+    /// Starts quickly and decelerates.
+    EaseOut,
+    /// Accelerates then decelerates.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Applies this curve to `t`, which is clamped to the range 0.0 to 1.0.
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - 2.0 * (1.0 - t) * (1.0 - t)
+                }
+            }
+        }
+    }
+}
+
+/// Values which can be blended between a start and end point.
+pub trait Lerp: Copy {
+    /// Returns the value a fraction `t` of the way from `self` to `to`.
+    fn lerp(self, to: Self, t: f64) -> Self;
+}
+
+impl Lerp for f64 {
+    fn lerp(self, to: Self, t: f64) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Lerp for u16 {
+    fn lerp(self, to: Self, t: f64) -> Self {
+        (self as f64).lerp(to as f64, t).round() as u16
+    }
+}
+
+impl Lerp for Position {
+    fn lerp(self, to: Self, t: f64) -> Self {
+        Position::new(self.x.lerp(to.x, t), self.y.lerp(to.y, t))
+    }
+}
+
+impl Lerp for Color {
+    /// Blends RGB colors channel by channel. Other colors cannot be blended
+    /// and switch over halfway through.
+    fn lerp(self, to: Self, t: f64) -> Self {
+        let channel = |a: u8, b: u8| (a as f64).lerp(b as f64, t).round() as u8;
+        match (self, to) {
+            (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+                Color::Rgb(channel(r1, r2), channel(g1, g2), channel(b1, b2))
+            }
+            _ if t < 0.5 => self,
+            _ => to,
+        }
+    }
+}
+
+/// Change in a value over a fixed period of time.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Tween<T> {
+    pub from: T,
+    pub to: T,
+    pub start: Instant,
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+impl<T: Lerp> Tween<T> {
+    pub fn new(from: T, to: T, start: Instant, duration: Duration) -> Self {
+        Self { from, to, start, duration, easing: Easing::default() }
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Fraction of the tween's duration which has elapsed at `now`, from 0.0
+    /// to 1.0.
+    pub fn progress(&self, now: Instant) -> f64 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        let elapsed = now.saturating_duration_since(self.start);
+        (elapsed.as_secs_f64() / self.duration.as_secs_f64()).min(1.0)
+    }
+
+    /// Value at time `now`. Returns `from` before the tween starts and `to`
+    /// once it has finished.
+    pub fn value(&self, now: Instant) -> T {
+        self.from.lerp(self.to, self.easing.apply(self.progress(now)))
+    }
+
+    pub fn is_finished(&self, now: Instant) -> bool {
+        self.progress(now) >= 1.0
+    }
+}
+
+/// Looping sequence of symbols, e.g. an enemy's walk cycle.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Sprite {
+    pub frames: &'static [&'static str],
+
+    /// Amount of `elapsed` for which each frame is displayed, in the same
+    /// units passed to [Self::frame]. This may be a time in seconds or any
+    /// other measure of progress, such as distance travelled.
+    pub frame_length: f64,
+}
+
+impl Sprite {
+    /// Frame to display once `elapsed` units have passed since the sequence
+    /// started.
+    pub fn frame(&self, elapsed: f64) -> &'static str {
+        if self.frames.is_empty() {
+            return " ";
+        }
+        let index =
+            if self.frame_length > 0.0 { (elapsed / self.frame_length) as usize } else { 0 };
+        self.frames[index % self.frames.len()]
+    }
+}
+
+/// Highlight displayed after a value changes, see
+/// [RenderContext::flash_on_change](crate::core::render_context::RenderContext::flash_on_change).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Flash {
+    /// True if the value went up, false if it went down.
+    pub increased: bool,
+    pub start: Instant,
+}
+
+impl Flash {
+    /// Color to draw the value with at `now`, fading from `highlight` back to
+    /// `normal` over [FLASH_DURATION].
+    pub fn color(&self, highlight: Color, normal: Color, now: Instant) -> Color {
+        Tween::new(highlight, normal, self.start, FLASH_DURATION).easing(Easing::EaseIn).value(now)
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ratatui::prelude::*;
+
+/// Characters for a horizontal bar filled to 1/8th, 2/8ths, etc. of a cell.
+const EIGHTHS: [&str; 8] = ["▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"];
+
+/// Draws a horizontal bar filling `fraction` of `area`'s first row, to the
+/// nearest eighth of a cell. At least one eighth is always filled, so a bar
+/// for a nonzero value never appears empty.
+pub fn render(fraction: f64, area: Rect, style: Style, buf: &mut Buffer) {
+    let total = (area.width * 8) as f64;
+    let eighths = ((fraction * total).round() as u16).max(1);
+    for (i, x) in (area.left()..area.right()).enumerate() {
+        let filled = eighths.saturating_sub(i as u16 * 8).min(8);
+        let symbol = if filled == 0 { " " } else { EIGHTHS[filled as usize - 1] };
+        buf.get_mut(x, area.y).set_symbol(symbol).set_style(style);
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crossterm::event::KeyCode;
 use data::core::actions::InterfaceAction;
 use data::core::colors;
 use data::core::widget_id::WidgetId;
@@ -20,6 +21,7 @@ use ratatui::symbols::border;
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use typed_builder::TypedBuilder;
 
+use crate::core::keys;
 use crate::core::render_context::RenderContext;
 
 #[derive(TypedBuilder)]
@@ -30,41 +32,143 @@ pub struct Button {
     #[builder(setter(into))]
     action: InterfaceAction,
     id: WidgetId,
+    /// Key which activates this button, shown before the label, e.g.
+    /// "[1] Arrow Tower".
+    #[builder(default, setter(strip_option))]
+    hotkey: Option<KeyCode>,
+    /// Symbol shown between the hotkey and the label, in its own color, e.g.
+    /// a tower's glyph. Unstyled icons use the label's color.
+    #[builder(default, setter(transform = |icon: impl Into<Span<'static>>| Some(icon.into())))]
+    icon: Option<Span<'static>>,
+    /// Additional line of text displayed in gold below the label, typically
+    /// a cost.
+    #[builder(default, setter(into, strip_option))]
+    secondary: Option<String>,
+    /// Reason this button cannot currently be used. Disabled buttons are
+    /// greyed out, ignore input, and show the reason as a tooltip on hover.
+    #[builder(default, setter(into, strip_option))]
+    disabled: Option<String>,
+    /// Highlights the button, e.g. for the active option in a group of
+    /// toggles.
+    #[builder(default)]
+    selected: bool,
+    #[builder(default)]
+    size: ButtonSize,
+}
+
+/// Visual size of a [Button].
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum ButtonSize {
+    /// Single line of text with no border.
+    Small,
+    /// Text surrounded by a single-line border.
+    Medium,
+    /// Text surrounded by a double-line border.
+    #[default]
+    Large,
 }
 
 impl StatefulWidget for Button {
     type State = RenderContext;
 
     fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        context.register(self.id, area);
         let hovered = context.hovered(self.id, area);
-        let pressed = context.mouse_down(self.id, area);
-        context.clicked(self.id, area, self.action);
+        let enabled = self.disabled.is_none();
+        let pressed = if let Some(reason) = &self.disabled {
+            context.hover_tooltip(self.id, reason.clone());
+            false
+        } else {
+            let pressed = context.mouse_down(self.id, area);
+            context.clicked(self.id, area, self.action);
+            if let Some(hotkey) = self.hotkey {
+                context.key_pressed(hotkey, self.action);
+            }
+            pressed
+        };
+
+        let style =
+            TextStyle { hovered: hovered && enabled, pressed, enabled, selected: self.selected };
+        let hint = self.hotkey.map(|k| format!("[{}] ", keys::name(k)));
+        let icon = self.icon.map(|icon| {
+            let color = style.color(icon.style.fg.unwrap_or(colors::white()));
+            style.apply(icon.fg(color))
+        });
+        let mut lines = self
+            .label
+            .split('\n')
+            .enumerate()
+            .map(|(i, s)| {
+                let mut spans = vec![];
+                if let (0, Some(hint)) = (i, &hint) {
+                    spans.push(style.apply(hint.clone().fg(style.color(colors::light_gray()))));
+                }
+                if let (0, Some(icon)) = (i, &icon) {
+                    spans.push(icon.clone());
+                    spans.push(style.apply(Span::raw(" ")));
+                }
+                spans.push(style.apply(s.fg(style.color(colors::white()))));
+                Line::from(spans)
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(secondary) = self.secondary {
+            let secondary = style.apply(secondary.fg(style.color(colors::yellow())));
+            if self.size == ButtonSize::Small {
+                if let Some(line) = lines.last_mut() {
+                    line.spans.push(style.apply(Span::raw(" ")));
+                    line.spans.push(secondary);
+                }
+            } else {
+                lines.push(Line::from(secondary));
+            }
+        }
 
-        Paragraph::new(
-            self.label
-                .split('\n')
-                .map(|s| Line::from(text_style(s, hovered, pressed)))
-                .collect::<Vec<_>>(),
-        )
-        .alignment(Alignment::Center)
-        .wrap(Wrap { trim: true })
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_set(border::DOUBLE)
-                .border_style(colors::white()),
-        )
-        .render(area, buf)
+        let border_color = if !enabled {
+            colors::dark_gray()
+        } else if self.selected {
+            colors::yellow()
+        } else {
+            colors::white()
+        };
+        let block = match self.size {
+            ButtonSize::Small => Block::default(),
+            ButtonSize::Medium => Block::default().borders(Borders::ALL).border_set(border::PLAIN),
+            ButtonSize::Large => Block::default().borders(Borders::ALL).border_set(border::DOUBLE),
+        };
+
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(block.border_style(border_color))
+            .render(area, buf)
     }
 }
 
-fn text_style(text: &str, hovered: bool, pressed: bool) -> Span {
-    let mut result = text.fg(colors::white());
-    result = if pressed { result.underlined() } else { result };
+#[derive(Copy, Clone)]
+struct TextStyle {
+    hovered: bool,
+    pressed: bool,
+    enabled: bool,
+    selected: bool,
+}
+
+impl TextStyle {
+    fn color(&self, enabled_color: Color) -> Color {
+        if self.enabled {
+            enabled_color
+        } else {
+            colors::dark_gray()
+        }
+    }
 
-    if hovered {
-        result.bg(colors::selected())
-    } else {
-        result
+    fn apply<'a>(&self, mut span: Span<'a>) -> Span<'a> {
+        span = if self.pressed { span.underlined() } else { span };
+        span = if self.selected { span.bold() } else { span };
+        if self.hovered {
+            span.bg(colors::selected())
+        } else {
+            span
+        }
     }
 }
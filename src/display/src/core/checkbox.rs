@@ -0,0 +1,68 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::actions::InterfaceAction;
+use data::core::colors;
+use data::core::widget_id::WidgetId;
+use ratatui::prelude::*;
+use typed_builder::TypedBuilder;
+
+use crate::core::render_context::RenderContext;
+
+/// Single line displaying a label with a box which is checked or unchecked.
+#[derive(TypedBuilder)]
+#[builder(builder_method(name = new))]
+pub struct Checkbox {
+    #[builder(setter(into))]
+    label: String,
+    checked: bool,
+    /// Action produced when the checkbox is clicked, typically toggling the
+    /// value it displays.
+    #[builder(setter(into))]
+    action: InterfaceAction,
+    id: WidgetId,
+}
+
+impl StatefulWidget for Checkbox {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let marker = if self.checked { "[x]" } else { "[ ]" };
+        render_option(self.id, marker, &self.label, self.action, area, buf, context);
+    }
+}
+
+/// Renders a clickable line containing a marker showing the state of an
+/// option, followed by its label.
+pub(crate) fn render_option(
+    id: WidgetId,
+    marker: &str,
+    label: &str,
+    action: InterfaceAction,
+    area: Rect,
+    buf: &mut Buffer,
+    context: &mut RenderContext,
+) {
+    context.register(id, area);
+    let hovered = context.hovered(id, area);
+    let pressed = context.mouse_down(id, area);
+    context.clicked(id, area, action);
+
+    let mut marker = marker.fg(colors::yellow());
+    marker = if pressed { marker.underlined() } else { marker };
+    Line::from(vec![marker, " ".into(), label.fg(colors::white())]).render(area, buf);
+    if hovered {
+        buf.set_style(area, Style::new().bg(colors::selected()));
+    }
+}
@@ -12,8 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Helpers for dividing the screen into rectangles.
+//!
+//! Widgets lay themselves out through the equivalent methods on
+//! [RenderContext](crate::core::render_context::RenderContext), which record
+//! every rectangle so that it can be outlined on screen for debugging.
+
+use data::core::colors;
+use data::core::map::{MAP_HEIGHT, MAP_WIDTH};
 use ratatui::layout::{Rect, Size};
 use ratatui::prelude::*;
+use ratatui::symbols::border;
+use ratatui::widgets::{Block, Borders};
+
+use crate::core::scale::Scale;
+
+/// Width of the game area when the map is drawn at [Scale::NORMAL].
+pub const GAME_WIDTH: u16 = 80;
+
+/// Height of the game area when the map is drawn at [Scale::NORMAL].
+pub const GAME_HEIGHT: u16 = 24;
+
+/// Width of each optional panel displayed beside the game area.
+pub const SIDE_PANEL_WIDTH: u16 = 30;
+
+/// Height of the heads-up display bar at the top of the game area.
+pub const HUD_HEIGHT: u16 = 1;
+
+/// Height of the status line at the bottom of the game area.
+pub const STATUS_HEIGHT: u16 = 1;
+
+/// Width of the tower shop panel on the right side of the game area.
+pub const SHOP_WIDTH: u16 = 20;
 
 /// Returns a rectangle of the given [Size] centered within the provided [Rect].
 ///
@@ -37,3 +67,260 @@ pub fn centered_rect(mut size: Size, rect: Rect) -> Rect {
     Layout::horizontal([Constraint::Fill(1), Constraint::Length(size.width), Constraint::Fill(1)])
         .split(vertical)[1]
 }
+
+/// Side of a rectangle.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Edge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// Splits a panel `size` rows or columns thick from the given [Edge] of
+/// `area`.
+///
+/// Returns the panel and the remaining space. The panel is clamped to fit
+/// within `area`.
+pub fn anchor(area: Rect, edge: Edge, size: u16) -> (Rect, Rect) {
+    let (panel, rest) = match edge {
+        Edge::Top => {
+            let size = size.min(area.height);
+            (Rect { height: size, ..area }, Rect {
+                y: area.y + size,
+                height: area.height - size,
+                ..area
+            })
+        }
+        Edge::Bottom => {
+            let size = size.min(area.height);
+            let rest = area.height - size;
+            (Rect { y: area.y + rest, height: size, ..area }, Rect { height: rest, ..area })
+        }
+        Edge::Left => {
+            let size = size.min(area.width);
+            (Rect { width: size, ..area }, Rect {
+                x: area.x + size,
+                width: area.width - size,
+                ..area
+            })
+        }
+        Edge::Right => {
+            let size = size.min(area.width);
+            let rest = area.width - size;
+            (Rect { x: area.x + rest, width: size, ..area }, Rect { width: rest, ..area })
+        }
+    };
+    (panel, rest)
+}
+
+/// Amount of space on each side of a rectangle.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Insets {
+    pub top: u16,
+    pub right: u16,
+    pub bottom: u16,
+    pub left: u16,
+}
+
+impl Insets {
+    pub const fn uniform(value: u16) -> Self {
+        Self { top: value, right: value, bottom: value, left: value }
+    }
+
+    pub const fn symmetric(horizontal: u16, vertical: u16) -> Self {
+        Self { top: vertical, right: horizontal, bottom: vertical, left: horizontal }
+    }
+}
+
+/// Shrinks `area` by the given [Insets], e.g. to add padding inside a panel
+/// or a margin around a widget.
+///
+/// If the insets are larger than `area`, returns an empty rectangle within
+/// it.
+pub fn inset(area: Rect, insets: Insets) -> Rect {
+    let left = insets.left.min(area.width);
+    let top = insets.top.min(area.height);
+    Rect {
+        x: area.x + left,
+        y: area.y + top,
+        width: area.width.saturating_sub(insets.left.saturating_add(insets.right)),
+        height: area.height.saturating_sub(insets.top.saturating_add(insets.bottom)),
+    }
+}
+
+/// Divides `area` into a grid of equally-sized cells, separated by
+/// `spacing` rows and columns.
+///
+/// Returns cells in row-major order, or nothing if `columns` or `rows` is
+/// zero.
+pub fn grid(area: Rect, columns: u16, rows: u16, spacing: u16) -> Vec<Rect> {
+    if columns == 0 || rows == 0 {
+        return vec![];
+    }
+
+    Layout::vertical(vec![Constraint::Fill(1); rows as usize])
+        .spacing(spacing)
+        .split(area)
+        .iter()
+        .flat_map(|row| {
+            Layout::horizontal(vec![Constraint::Fill(1); columns as usize])
+                .spacing(spacing)
+                .split(*row)
+                .to_vec()
+        })
+        .collect()
+}
+
+/// Regions of the game area.
+///
+/// The game area has no border of its own, so that the map and shop can use
+/// all of [GAME_WIDTH] by [GAME_HEIGHT] with the HUD across the full width.
+/// A border is drawn around it at [ScreenLayout::outline] when there is room.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct GameLayout {
+    /// Bar at the top displaying resources and wave progress.
+    pub hud: Rect,
+    /// Panel on the right for purchasing and inspecting towers.
+    pub shop: Rect,
+    /// Line at the bottom for messages and hints.
+    pub status: Rect,
+    /// Remaining space, where the map is displayed.
+    pub battlefield: Rect,
+}
+
+impl GameLayout {
+    pub fn new(area: Rect) -> Self {
+        let (hud, rest) = anchor(area, Edge::Top, HUD_HEIGHT);
+        let (status, rest) = anchor(rest, Edge::Bottom, STATUS_HEIGHT);
+        let (shop, battlefield) = anchor(rest, Edge::Right, SHOP_WIDTH);
+        Self { hud, shop, status, battlefield }
+    }
+
+    /// Every region, for the debug overlay.
+    pub fn rects(&self) -> [Rect; 4] {
+        [self.hud, self.shop, self.status, self.battlefield]
+    }
+}
+
+/// Optional features of a [ScreenLayout].
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct ScreenOptions {
+    /// Display side panels beside the game area if there is room.
+    pub side_panels: bool,
+    /// Enlarge the game area to draw the map at a larger [Scale] if there is
+    /// room.
+    pub scale_map: bool,
+}
+
+/// Arrangement of the whole terminal.
+///
+/// The game is displayed in an area of at least [GAME_WIDTH] by
+/// [GAME_HEIGHT]. Extra space is used to enlarge the map, if enabled, then
+/// for an outline around the game area, then for side panels on the right
+/// and left, with the whole arrangement centered on screen.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ScreenLayout {
+    /// Area containing the game, see [GameLayout].
+    pub game: Rect,
+    /// Number of cells used to draw each map tile within `game`.
+    pub scale: Scale,
+    /// Area for a border around `game`, if there is room.
+    pub outline: Option<Rect>,
+    pub left_panel: Option<Rect>,
+    pub right_panel: Option<Rect>,
+}
+
+impl ScreenLayout {
+    /// True if `area` is large enough to display the game.
+    pub fn fits(area: Rect) -> bool {
+        area.width >= GAME_WIDTH && area.height >= GAME_HEIGHT
+    }
+
+    /// Every region which is present, for the debug overlay.
+    pub fn rects(&self) -> Vec<Rect> {
+        [Some(self.game), self.outline, self.left_panel, self.right_panel]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// Arranges the screen. Returns `None` if `area` is smaller than the
+    /// minimum game area.
+    pub fn new(area: Rect, options: ScreenOptions) -> Option<Self> {
+        if !Self::fits(area) {
+            return None;
+        }
+
+        let fits = |size: Size| size.width <= area.width && size.height <= area.height;
+        let scale = Scale::ALL
+            .into_iter()
+            .filter(|s| options.scale_map || *s == Scale::NORMAL)
+            .find(|s| fits(game_size(*s)))
+            .unwrap_or(Scale::NORMAL);
+        let game = game_size(scale);
+
+        let outlined = fits(Size { width: game.width + 2, height: game.height + 2 });
+        let border = if outlined { 2 } else { 0 };
+        let center = Size { width: game.width + border, height: game.height + border };
+        let panels = if !options.side_panels || !outlined {
+            0
+        } else {
+            ((area.width - center.width) / SIDE_PANEL_WIDTH).min(2)
+        };
+
+        let group = centered_rect(
+            Size { width: center.width + panels * SIDE_PANEL_WIDTH, height: center.height },
+            area,
+        );
+        let (left_panel, rest) = if panels == 2 {
+            let (panel, rest) = anchor(group, Edge::Left, SIDE_PANEL_WIDTH);
+            (Some(panel), rest)
+        } else {
+            (None, group)
+        };
+        let (right_panel, center) = if panels >= 1 {
+            let (panel, rest) = anchor(rest, Edge::Right, SIDE_PANEL_WIDTH);
+            (Some(panel), rest)
+        } else {
+            (None, rest)
+        };
+
+        Some(if outlined {
+            Self {
+                game: inset(center, Insets::uniform(1)),
+                scale,
+                outline: Some(center),
+                left_panel,
+                right_panel,
+            }
+        } else {
+            Self { game: center, scale, outline: None, left_panel, right_panel }
+        })
+    }
+}
+
+/// Size of a game area which draws the map at the given [Scale].
+pub fn game_size(scale: Scale) -> Size {
+    let map = scale.apply(Size { width: MAP_WIDTH, height: MAP_HEIGHT });
+    Size { width: map.width + SHOP_WIDTH, height: map.height + HUD_HEIGHT + STATUS_HEIGHT }
+}
+
+/// Outlines each of `rects` and labels it with its size.
+pub fn render_debug_overlay(rects: &[Rect], buf: &mut Buffer) {
+    let bounds = buf.area;
+    for rect in rects.iter().map(|r| r.intersection(bounds)) {
+        if rect.is_empty() {
+            continue;
+        }
+        Block::default()
+            .borders(Borders::ALL)
+            .border_set(border::PLAIN)
+            .border_style(colors::red())
+            .render(rect, buf);
+        Line::from(format!("{}x{}", rect.width, rect.height).fg(colors::red())).render(
+            Rect { x: rect.x + 1, width: rect.width.saturating_sub(2), height: 1, ..rect },
+            buf,
+        );
+    }
+}
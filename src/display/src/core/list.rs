@@ -0,0 +1,55 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::actions::InterfaceAction;
+use data::core::widget_id::WidgetId;
+use ratatui::prelude::*;
+use typed_builder::TypedBuilder;
+
+use crate::core::render_context::RenderContext;
+use crate::core::table::Table;
+
+/// Scrollable list of items, one of which may be selected.
+///
+/// Behaves like a [Table] with a single column and no header.
+#[derive(TypedBuilder)]
+#[builder(builder_method(name = new))]
+pub struct List {
+    id: WidgetId,
+    items: Vec<Line<'static>>,
+    #[builder(default)]
+    selected: Option<usize>,
+    /// Produces the action for selecting the item at a given index.
+    #[builder(setter(transform = |f: impl Fn(usize) -> InterfaceAction + 'static| Box::new(f) as Box<dyn Fn(usize) -> InterfaceAction>))]
+    on_select: Box<dyn Fn(usize) -> InterfaceAction>,
+    /// Whether this list receives keyboard input.
+    #[builder(default)]
+    focused: bool,
+}
+
+impl StatefulWidget for List {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        Table::new()
+            .id(self.id)
+            .widths(vec![Constraint::Fill(1)])
+            .rows(self.items.into_iter().map(|item| vec![item]).collect())
+            .selected(self.selected)
+            .on_select(self.on_select)
+            .focused(self.focused)
+            .build()
+            .render(area, buf, context)
+    }
+}
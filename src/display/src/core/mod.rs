@@ -12,6 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod animation;
+pub mod bar;
 pub mod button;
+pub mod checkbox;
+pub mod keys;
 pub mod layout;
+pub mod list;
+pub mod radio_group;
 pub mod render_context;
+pub mod scale;
+pub mod slider;
+pub mod table;
+pub mod text_input;
+pub mod tooltip;
+pub mod window;
@@ -0,0 +1,49 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::actions::InterfaceAction;
+use data::core::widget_id::WidgetId;
+use ratatui::prelude::*;
+use typed_builder::TypedBuilder;
+
+use crate::core::checkbox;
+use crate::core::render_context::RenderContext;
+
+/// Group of mutually exclusive options, one per line, of which exactly one
+/// is selected.
+///
+/// Each option is identified by `id.index(option)`.
+#[derive(TypedBuilder)]
+#[builder(builder_method(name = new))]
+pub struct RadioGroup {
+    id: WidgetId,
+    options: Vec<String>,
+    selected: usize,
+    /// Produces the action for selecting the option at a given index.
+    #[builder(setter(transform = |f: impl Fn(usize) -> InterfaceAction + 'static| Box::new(f) as Box<dyn Fn(usize) -> InterfaceAction>))]
+    on_select: Box<dyn Fn(usize) -> InterfaceAction>,
+}
+
+impl StatefulWidget for RadioGroup {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let rows = Layout::vertical(vec![Constraint::Length(1); self.options.len()]).split(area);
+        for (i, (label, row)) in self.options.iter().zip(rows.iter()).enumerate() {
+            let marker = if i == self.selected { "(•)" } else { "( )" };
+            let action = (self.on_select)(i);
+            checkbox::render_option(self.id.index(i), marker, label, action, *row, buf, context);
+        }
+    }
+}
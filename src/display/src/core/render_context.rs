@@ -12,34 +12,232 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::mem;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEvent, MouseEventKind};
 use data::core::actions::InterfaceAction;
+use data::core::colors::Palette;
 use data::core::widget_id::WidgetId;
-use ratatui::layout::Position;
+use ratatui::layout::{Position, Size};
 use ratatui::prelude::*;
-use tracing::info;
+use tracing::{error, info};
+
+use crate::core::animation::{Flash, FLASH_DURATION, FRAME_INTERVAL};
+use crate::core::layout;
+use crate::core::layout::{Edge, Insets};
+use crate::core::text_input::TextInputState;
+use crate::core::tooltip::{Tooltip, TOOLTIP_DELAY};
 
+/// State shared between widgets while rendering a frame.
+///
+/// Input events are queued and delivered to widgets in batches, one batch per
+/// frame. Each batch ends with the first event which can complete an
+/// interaction, i.e. a key press, paste or mouse button release, so that
+/// every such event is handled in the order it occurred and sees the state
+/// produced by the events before it. Widgets check for input in the order
+/// they are rendered against the state from before the frame, so delivering
+/// two such events together could reorder their actions or deliver the
+/// second to the wrong widget, e.g. a key pressed just after Esc opens the
+/// pause menu would still reach the game beneath it. The cost is one frame
+/// per event which produces actions, plus one more frame for the result to
+/// be displayed before the next batch. Actions produced by widgets are
+/// collected in the order they occur and returned from [Self::finish_render].
+///
+/// Widgets are stacked in the order they are rendered, with later widgets on
+/// top. Mouse input is only delivered to the topmost widget under the cursor,
+/// based on the stacking order from the previous frame. Keyboard input is
+/// only delivered to widgets in the topmost modal layer of the previous
+/// frame, and keys used for editing text are only delivered to a focused
+/// text input while there is one.
 #[derive(Default)]
 pub struct RenderContext {
-    event: Option<Event>,
+    queued: VecDeque<Event>,
+    events: Vec<Event>,
+
+    /// True if the previous frame produced actions, in which case the next
+    /// frame is rendered without delivering new input so that it reflects
+    /// their effects.
+    unsettled: bool,
     current_hover: Option<WidgetId>,
+    hover_start: Option<Instant>,
+    mouse_position: Option<Position>,
+    tick_progress: f64,
+    palette: Palette,
+    now: Option<Instant>,
+    redraw_at: Option<Instant>,
     current_mouse_down: Option<WidgetId>,
+    scroll_offsets: HashMap<WidgetId, usize>,
+    text_inputs: HashMap<WidgetId, TextInputState>,
+    highlighted: HashMap<WidgetId, usize>,
     exit: bool,
-    action: Option<InterfaceAction>,
+
+    /// Rectangles laid out this frame, while the debug overlay is enabled.
+    debug_rects: Option<Vec<Rect>>,
+    actions: Vec<InterfaceAction>,
+    rendered: HashSet<WidgetId>,
+    hit_regions: Vec<HitRegion>,
+    previous_hit_regions: Vec<HitRegion>,
+    tooltip: Option<Tooltip>,
+    tracked_values: HashMap<WidgetId, TrackedValue>,
+
+    /// Number of modal layers started so far this frame.
+    layer: usize,
+
+    /// Number of modal layers in the previous frame. Widgets in lower layers
+    /// do not receive input.
+    input_layer: usize,
+
+    /// Layer containing a focused text input this frame, if any.
+    text_focus: Option<usize>,
+
+    /// True if a text input in the topmost layer was focused in the previous
+    /// frame, in which case it receives all text editing keys.
+    keyboard_captured: bool,
+}
+
+/// Value displayed by a widget, used to detect changes between frames.
+#[derive(Debug, Copy, Clone)]
+struct TrackedValue {
+    value: i64,
+    flash: Option<Flash>,
+}
+
+/// Area of the screen which receives mouse input.
+#[derive(Debug, Copy, Clone)]
+struct HitRegion {
+    /// Widget which receives input in this area, or `None` for a region which
+    /// blocks input to everything beneath it.
+    target: Option<WidgetId>,
+    area: Rect,
 }
 
 impl RenderContext {
-    pub fn set_last_event(&mut self, event: Option<Event>) {
-        if let Some(Event::Key(e)) = event {
-            if e.kind == KeyEventKind::Press && e.code == KeyCode::Char('q') {
-                self.exit = true;
+    /// Queues an input event to be delivered to widgets in a later frame.
+    pub fn push_event(&mut self, event: Event) {
+        self.queued.push_back(event);
+    }
+
+    /// True if there are input events which have not yet been delivered, in
+    /// which case another frame should be rendered.
+    pub fn has_queued_events(&self) -> bool {
+        !self.queued.is_empty()
+    }
+
+    /// Input events delivered this frame, in the order they occurred.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Prepares to render a frame at time `now`, delivering the next batch of
+    /// queued input events unless the previous frame produced actions. Should
+    /// be called before rendering each frame.
+    pub fn start_frame(&mut self, now: Instant) {
+        self.now = Some(now);
+        self.redraw_at = None;
+        if self.unsettled {
+            return;
+        }
+        while let Some(event) = self.queued.pop_front() {
+            let last = ends_batch(&event);
+            if let Event::Mouse(e) = event {
+                self.mouse_position = Some(Position::new(e.column, e.row));
+            }
+            self.events.push(event);
+            if last {
+                break;
+            }
+        }
+    }
+
+    /// Records that the widget with the given ID is being rendered in `area`
+    /// this frame, on top of all previously-registered widgets.
+    ///
+    /// Interactive widgets must call this once per frame before checking for
+    /// input. Rendering two widgets with the same ID in a single frame is an
+    /// error, which panics in debug builds.
+    pub fn register(&mut self, id: WidgetId, area: Rect) {
+        self.hit_regions.push(HitRegion { target: Some(id), area });
+        if !self.rendered.insert(id) {
+            if cfg!(debug_assertions) {
+                panic!("Duplicate widget ID {id} rendered in a single frame");
+            } else {
+                error!(%id, "Duplicate widget ID rendered in a single frame");
+            }
+        }
+    }
+
+    /// Starts a modal layer. Widgets rendered before this call do not receive
+    /// mouse or keyboard input.
+    pub fn modal_layer(&mut self) {
+        self.layer += 1;
+        self.hit_regions
+            .push(HitRegion { target: None, area: Rect::new(0, 0, u16::MAX, u16::MAX) });
+    }
+
+    /// Time at which the next frame should be rendered even if no input is
+    /// received, e.g. in order to display a tooltip.
+    pub fn redraw_at(&self) -> Option<Instant> {
+        self.redraw_at
+    }
+
+    /// Time at which the current frame is being rendered.
+    pub fn now(&self) -> Instant {
+        self.now.unwrap_or_else(Instant::now)
+    }
+
+    /// Requests that a frame be rendered at the given time, e.g. to continue
+    /// an animation. The earliest requested time this frame wins.
+    pub fn request_redraw(&mut self, at: Instant) {
+        self.redraw_at = Some(self.redraw_at.map_or(at, |r| r.min(at)));
+    }
+
+    /// Amount of time the mouse has been over the widget with the given ID,
+    /// or `None` if it is not currently hovered.
+    pub fn hover_duration(&self, id: WidgetId) -> Option<Duration> {
+        if self.current_hover != Some(id) {
+            return None;
+        }
+        match (self.now, self.hover_start) {
+            (Some(now), Some(start)) => Some(now.saturating_duration_since(start)),
+            _ => Some(Duration::ZERO),
+        }
+    }
+
+    /// Displays `text` in a [Tooltip] next to the cursor once the mouse has
+    /// been over the widget with the given ID for [TOOLTIP_DELAY].
+    ///
+    /// Only one tooltip is displayed per frame, the last one requested wins.
+    pub fn hover_tooltip(&mut self, id: WidgetId, text: impl Into<Text<'static>>) {
+        let Some(duration) = self.hover_duration(id) else {
+            return;
+        };
+
+        if duration >= TOOLTIP_DELAY {
+            if let Some(position) = self.mouse_position {
+                self.tooltip = Some(Tooltip { text: text.into(), position });
             }
+        } else if let Some(now) = self.now {
+            self.request_redraw(now + (TOOLTIP_DELAY - duration));
         }
+    }
+
+    /// Returns the tooltip requested this frame, if any. Should be called
+    /// after all other widgets have been rendered.
+    pub fn take_tooltip(&mut self) -> Option<Tooltip> {
+        self.tooltip.take()
+    }
 
-        self.event = event;
+    /// Widget which the mouse is currently over.
+    pub fn current_hover(&self) -> Option<WidgetId> {
+        self.current_hover
     }
 
     pub fn set_current_hover(&mut self, current: Option<WidgetId>) {
+        if current != self.current_hover {
+            self.hover_start = self.now;
+        }
         self.current_hover = current;
     }
 
@@ -47,37 +245,177 @@ impl RenderContext {
         self.current_mouse_down = current;
     }
 
+    /// Index of the first visible row of the scrollable widget with the
+    /// given ID.
+    pub fn scroll_offset(&self, id: WidgetId) -> usize {
+        self.scroll_offsets.get(&id).copied().unwrap_or_default()
+    }
+
+    pub fn set_scroll_offset(&mut self, id: WidgetId, offset: usize) {
+        self.scroll_offsets.insert(id, offset);
+    }
+
+    /// Current contents of the text input with the given ID.
+    pub fn text_input(&self, id: WidgetId) -> &str {
+        self.text_inputs.get(&id).map_or("", |s| s.text())
+    }
+
+    /// Editing state of the text input with the given ID.
+    pub fn text_input_state(&self, id: WidgetId) -> TextInputState {
+        self.text_inputs.get(&id).cloned().unwrap_or_default()
+    }
+
+    /// Replaces the editing state of the text input with the given ID, e.g.
+    /// to provide its initial contents via [TextInputState::new].
+    pub fn set_text_input(&mut self, id: WidgetId, state: TextInputState) {
+        self.text_inputs.insert(id, state);
+    }
+
+    /// Index of the item highlighted for keyboard selection within the
+    /// widget with the given ID, e.g. a row of a menu.
+    pub fn highlighted(&self, id: WidgetId) -> Option<usize> {
+        self.highlighted.get(&id).copied()
+    }
+
+    pub fn set_highlighted(&mut self, id: WidgetId, index: usize) {
+        self.highlighted.insert(id, index);
+    }
+
+    /// Records the `value` currently displayed by the widget with the given
+    /// ID, returning a [Flash] if it differs from the value recorded on a
+    /// previous frame within the last [FLASH_DURATION].
+    ///
+    /// Nothing is returned the first time a value is recorded.
+    pub fn flash_on_change(&mut self, id: WidgetId, value: i64) -> Option<Flash> {
+        let now = self.now();
+        let tracked = self.tracked_values.entry(id).or_insert(TrackedValue { value, flash: None });
+        if tracked.value != value {
+            tracked.flash = Some(Flash { increased: value > tracked.value, start: now });
+            tracked.value = value;
+        }
+
+        let flash =
+            tracked.flash.filter(|f| now.saturating_duration_since(f.start) < FLASH_DURATION);
+        if flash.is_some() {
+            self.request_redraw(now + FRAME_INTERVAL);
+        }
+        flash
+    }
+
+    /// Fraction of the time between the previous simulation tick and the
+    /// next one which has elapsed, from 0.0 to 1.0. Used to interpolate
+    /// motion between ticks.
+    pub fn tick_progress(&self) -> f64 {
+        self.tick_progress
+    }
+
+    pub fn set_tick_progress(&mut self, progress: f64) {
+        self.tick_progress = progress.clamp(0.0, 1.0);
+    }
+
+    /// Palette used to display the colors drawn by widgets, applied to the
+    /// whole frame once it has been rendered.
+    pub fn palette(&self) -> Palette {
+        self.palette
+    }
+
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.palette = palette;
+    }
+
+    /// Starts recording the rectangles laid out by widgets for the rest of
+    /// this frame, to be outlined by [Self::render_debug_overlay].
+    pub fn start_debug_overlay(&mut self) {
+        self.debug_rects = Some(vec![]);
+    }
+
+    /// Outlines every rectangle recorded since [Self::start_debug_overlay]
+    /// and labels it with its size, then stops recording.
+    pub fn render_debug_overlay(&mut self, buf: &mut Buffer) {
+        layout::render_debug_overlay(&self.debug_rects.take().unwrap_or_default(), buf);
+    }
+
+    /// Records `rect` for the debug overlay, if it is enabled, and returns
+    /// it unchanged.
+    pub fn record_layout(&mut self, rect: Rect) -> Rect {
+        if let Some(rects) = &mut self.debug_rects {
+            rects.push(rect);
+        }
+        rect
+    }
+
+    /// [layout::centered_rect], recording the result.
+    pub fn centered_rect(&mut self, size: Size, rect: Rect) -> Rect {
+        self.record_layout(layout::centered_rect(size, rect))
+    }
+
+    /// [layout::anchor], recording the panel.
+    pub fn anchor(&mut self, area: Rect, edge: Edge, size: u16) -> (Rect, Rect) {
+        let (panel, rest) = layout::anchor(area, edge, size);
+        (self.record_layout(panel), rest)
+    }
+
+    /// [layout::inset], recording the result.
+    pub fn inset(&mut self, area: Rect, insets: Insets) -> Rect {
+        self.record_layout(layout::inset(area, insets))
+    }
+
+    /// [layout::grid], recording each cell.
+    pub fn grid(&mut self, area: Rect, columns: u16, rows: u16, spacing: u16) -> Vec<Rect> {
+        layout::grid(area, columns, rows, spacing)
+            .into_iter()
+            .map(|r| self.record_layout(r))
+            .collect()
+    }
+
+    /// Forgets values observed in the previous game, so that nothing
+    /// flashes when a new game starts.
+    pub fn start_new_game(&mut self) {
+        self.tracked_values.clear();
+    }
+
+    pub fn set_should_exit(&mut self) {
+        self.exit = true;
+    }
+
     pub fn should_exit(&self) -> bool {
         self.exit
     }
 
-    pub fn finish_render(&mut self) -> Option<InterfaceAction> {
-        let action = self.action;
-        self.action = None;
-
-        if matches!(self.event, Some(Event::Mouse(e))
-            if e.kind == MouseEventKind::Up(MouseButton::Left))
+    /// Completes rendering of a frame, clearing the queued input events and
+    /// returning all actions produced by widgets during this frame.
+    pub fn finish_render(&mut self) -> Vec<InterfaceAction> {
+        if matches!(self.mouse_events().filter(|e| is_left_button_event(e)).last(),
+            Some(e) if e.kind == MouseEventKind::Up(MouseButton::Left))
         {
             // Stop press event states on mouse up
             self.current_mouse_down = None;
         }
 
-        self.event = None;
-        action
+        self.events.clear();
+        self.rendered.clear();
+        self.tooltip = None;
+        self.previous_hit_regions = mem::take(&mut self.hit_regions);
+        self.unsettled = !self.actions.is_empty();
+        self.keyboard_captured = self.text_focus == Some(self.layer);
+        self.input_layer = mem::take(&mut self.layer);
+        self.text_focus = None;
+        mem::take(&mut self.actions)
     }
 
     pub fn hovered(&mut self, id: WidgetId, area: Rect) -> bool {
         let current = self.current_hover == Some(id);
-        let Some(Event::Mouse(e)) = self.event else {
+        let Some(e) =
+            self.mouse_events().filter(|e| e.kind == MouseEventKind::Moved).last().copied()
+        else {
             return current;
         };
 
-        if e.kind == MouseEventKind::Moved {
-            if area.contains(Position::new(e.column, e.row)) {
-                self.action = Some(InterfaceAction::SetHover(Some(id)));
-            } else if current && self.action.is_none() {
-                self.action = Some(InterfaceAction::SetHover(None));
-            }
+        if self.is_topmost(id, area, &e) {
+            self.actions.push(InterfaceAction::SetHover(Some(id)));
+        } else if current && !self.actions.iter().any(|a| matches!(a, InterfaceAction::SetHover(_)))
+        {
+            self.actions.push(InterfaceAction::SetHover(None));
         }
 
         current
@@ -85,29 +423,258 @@ impl RenderContext {
 
     pub fn mouse_down(&mut self, id: WidgetId, area: Rect) -> bool {
         let current = self.current_mouse_down == Some(id);
-        let Some(Event::Mouse(e)) = self.event else {
+        let Some(e) = self
+            .mouse_events()
+            .filter(|e| e.kind == MouseEventKind::Down(MouseButton::Left))
+            .last()
+            .copied()
+        else {
             return current;
         };
 
-        if e.kind == MouseEventKind::Down(MouseButton::Left) {
-            if area.contains(Position::new(e.column, e.row)) {
-                self.action = Some(InterfaceAction::SetMouseDown(Some(id)));
-            } else if current && self.action.is_none() {
-                self.action = Some(InterfaceAction::SetHover(None));
-            }
+        if self.is_topmost(id, area, &e) {
+            self.actions.push(InterfaceAction::SetMouseDown(Some(id)));
+        } else if current
+            && !self.actions.iter().any(|a| matches!(a, InterfaceAction::SetMouseDown(_)))
+        {
+            self.actions.push(InterfaceAction::SetMouseDown(None));
         }
 
         current
     }
 
+    /// Latest position of the mouse this frame while the left button is held
+    /// down after being pressed over the widget with the given ID, including
+    /// the position where it was pressed. The position may be outside of
+    /// `area` if the mouse was dragged away.
+    ///
+    /// Returns `None` if the widget was not pressed or the mouse did not move
+    /// while pressed this frame. Widgets must also call [Self::mouse_down]
+    /// each frame in order to track drags which span multiple frames.
+    pub fn dragged(&self, id: WidgetId, area: Rect) -> Option<Position> {
+        let mut down = self.current_mouse_down == Some(id);
+        let mut result = None;
+        for e in self.mouse_events() {
+            let position = Position::new(e.column, e.row);
+            match e.kind {
+                MouseEventKind::Down(MouseButton::Left) => {
+                    down = self.is_topmost(id, area, e);
+                    if down {
+                        result = Some(position);
+                    }
+                }
+                MouseEventKind::Drag(MouseButton::Left) if down => {
+                    result = Some(position);
+                }
+                MouseEventKind::Up(MouseButton::Left) => {
+                    down = false;
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+
+    /// Produces the provided action once for each time the widget was clicked
+    /// this frame, i.e. the left mouse button was both pressed and released
+    /// within `area`.
     pub fn clicked(&mut self, id: WidgetId, area: Rect, action: impl Into<InterfaceAction>) {
-        if matches!(self.event, Some(Event::Mouse(e))
-            if e.kind == MouseEventKind::Up(MouseButton::Left)
-               && area.contains(Position::new(e.column, e.row))
-               && self.current_mouse_down == Some(id))
-        {
-            info!(?id, "Widget clicked");
-            self.action = Some(action.into());
+        let action = action.into();
+        for _ in self.click_positions(id, area) {
+            info!(%id, "Widget clicked");
+            self.actions.push(action);
         }
     }
+
+    /// Positions at which the widget was clicked this frame, in order.
+    ///
+    /// Widgets which are divided into several parts, such as a grid of
+    /// tiles, can use this to determine which part was clicked.
+    pub fn click_positions(&self, id: WidgetId, area: Rect) -> Vec<Position> {
+        let mut down = self.current_mouse_down == Some(id);
+        let mut result = vec![];
+        for e in self.mouse_events() {
+            let inside = self.is_topmost(id, area, e);
+            match e.kind {
+                MouseEventKind::Down(MouseButton::Left) => {
+                    down = inside;
+                }
+                MouseEventKind::Up(MouseButton::Left) => {
+                    if down && inside {
+                        result.push(Position::new(e.column, e.row));
+                    }
+                    down = false;
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+
+    /// Position of the mouse cursor as of the most recent mouse event.
+    ///
+    /// Combined with [Self::hovered], this determines which part of a widget
+    /// the mouse is over.
+    pub fn mouse_position(&self) -> Option<Position> {
+        self.mouse_position
+    }
+
+    /// Net number of rows the mouse wheel was scrolled over the widget this
+    /// frame, positive for scrolling down.
+    ///
+    /// Unlike other mouse input, scrolling is also delivered to a widget when
+    /// the cursor is over one of its children.
+    pub fn scrolled(&self, id: WidgetId, area: Rect) -> isize {
+        self.mouse_events()
+            .filter(|e| self.is_topmost_ancestor(id, area, e))
+            .map(|e| match e.kind {
+                MouseEventKind::ScrollDown => 1,
+                MouseEventKind::ScrollUp => -1,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Updates the scroll offset of the widget with the given ID in the next
+    /// frame.
+    pub fn scroll_to(&mut self, id: WidgetId, offset: usize) {
+        if offset != self.scroll_offset(id) {
+            self.actions.push(InterfaceAction::SetScrollOffset(id, offset));
+        }
+    }
+
+    /// Produces an action in response to input which is not covered by the
+    /// other methods on this type.
+    pub fn push_action(&mut self, action: impl Into<InterfaceAction>) {
+        self.actions.push(action.into());
+    }
+
+    /// Produces the provided action if the key with the given [KeyCode] was
+    /// pressed this frame.
+    ///
+    /// At most one key press is delivered per frame, so repeated presses each
+    /// produce the action in a separate frame.
+    pub fn key_pressed(&mut self, code: KeyCode, action: impl Into<InterfaceAction>) {
+        if self.key_presses().any(|c| c == code) {
+            info!(?code, "Key pressed");
+            self.actions.push(action.into());
+        }
+    }
+
+    /// Produces an action for the first key pressed this frame, if any.
+    pub fn any_key_pressed(&mut self, action: impl FnOnce(KeyCode) -> InterfaceAction) {
+        let code = self.key_presses().next();
+        if let Some(code) = code {
+            self.actions.push(action(code));
+        }
+    }
+
+    /// True if widgets currently being rendered receive input, i.e. they are
+    /// not beneath a modal layer which was displayed in the previous frame.
+    pub fn receives_input(&self) -> bool {
+        self.layer >= self.input_layer
+    }
+
+    /// Records that a text input is focused, so that it receives all text
+    /// editing keys in the next frame instead of other widgets' hotkeys.
+    pub fn focus_text_input(&mut self) {
+        if self.receives_input() {
+            self.text_focus = Some(self.layer);
+        }
+    }
+
+    /// True if the mouse event occurred within `area` and no widget was
+    /// rendered on top of the widget with the given ID at that position
+    /// during the previous frame.
+    ///
+    /// Widgets which were not rendered in the previous frame are treated as
+    /// topmost, unless they are beneath a modal layer.
+    fn is_topmost(&self, id: WidgetId, area: Rect, event: &MouseEvent) -> bool {
+        self.is_unobscured(id, area, event, |_| true)
+    }
+
+    /// As [Self::is_topmost], but ignores widgets which are children of the
+    /// widget with the given ID.
+    fn is_topmost_ancestor(&self, id: WidgetId, area: Rect, event: &MouseEvent) -> bool {
+        self.is_unobscured(id, area, event, |r| !r.target.is_some_and(|t| id.is_ancestor_of(t)))
+    }
+
+    fn is_unobscured(
+        &self,
+        id: WidgetId,
+        area: Rect,
+        event: &MouseEvent,
+        obscures: impl Fn(&HitRegion) -> bool,
+    ) -> bool {
+        let position = Position::new(event.column, event.row);
+        if !area.contains(position) || !self.receives_input() {
+            return false;
+        }
+
+        match self.previous_hit_regions.iter().position(|r| r.target == Some(id)) {
+            Some(index) => !self.previous_hit_regions[index + 1..]
+                .iter()
+                .any(|r| obscures(r) && r.area.contains(position)),
+            None => true,
+        }
+    }
+
+    /// Keys pressed this frame which are delivered to the widget currently
+    /// being rendered, in the order they were pressed.
+    ///
+    /// Nothing is delivered beneath a modal layer, and text editing keys are
+    /// not delivered while a text input is focused.
+    pub fn key_presses(&self) -> impl Iterator<Item = KeyCode> + '_ {
+        let receives = self.receives_input();
+        self.events.iter().filter_map(move |e| match e {
+            Event::Key(e)
+                if receives
+                    && e.kind == KeyEventKind::Press
+                    && !(self.keyboard_captured && is_text_editing(e.code)) =>
+            {
+                Some(e.code)
+            }
+            _ => None,
+        })
+    }
+
+    fn mouse_events(&self) -> impl Iterator<Item = &MouseEvent> + '_ {
+        self.events.iter().filter_map(|e| match e {
+            Event::Mouse(e) => Some(e),
+            _ => None,
+        })
+    }
+}
+
+/// True if a focused text input uses the key with the given [KeyCode].
+fn is_text_editing(code: KeyCode) -> bool {
+    matches!(
+        code,
+        KeyCode::Char(_)
+            | KeyCode::Backspace
+            | KeyCode::Delete
+            | KeyCode::Left
+            | KeyCode::Right
+            | KeyCode::Home
+            | KeyCode::End
+            | KeyCode::Enter
+    )
+}
+
+/// True if `event` should be the last event delivered in a frame, see
+/// [RenderContext].
+fn ends_batch(event: &Event) -> bool {
+    match event {
+        Event::Key(e) => e.kind == KeyEventKind::Press,
+        Event::Paste(_) => true,
+        Event::Mouse(e) => matches!(e.kind, MouseEventKind::Up(_)),
+        _ => false,
+    }
+}
+
+fn is_left_button_event(e: &MouseEvent) -> bool {
+    matches!(
+        e.kind,
+        MouseEventKind::Down(MouseButton::Left) | MouseEventKind::Up(MouseButton::Left)
+    )
 }
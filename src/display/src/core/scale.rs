@@ -0,0 +1,88 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::map::TilePosition;
+use ratatui::layout::{Position, Rect, Size};
+
+/// Number of terminal cells used to draw each tile of a grid.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Scale {
+    pub columns: u16,
+    pub rows: u16,
+}
+
+impl Scale {
+    /// All scales, from largest to smallest.
+    pub const ALL: [Scale; 3] = [Scale::LARGE, Scale::WIDE, Scale::NORMAL];
+    /// A block of four by two cells per tile.
+    pub const LARGE: Scale = Scale { columns: 4, rows: 2 };
+    /// One cell per tile.
+    pub const NORMAL: Scale = Scale { columns: 1, rows: 1 };
+    /// Two cells side by side per tile. Terminal cells are roughly twice as
+    /// tall as they are wide, so this makes tiles approximately square.
+    pub const WIDE: Scale = Scale { columns: 2, rows: 1 };
+
+    /// Number of cells needed to draw a grid of the given size.
+    pub fn apply(self, grid: Size) -> Size {
+        Size { width: grid.width * self.columns, height: grid.height * self.rows }
+    }
+}
+
+impl Default for Scale {
+    fn default() -> Self {
+        Self::NORMAL
+    }
+}
+
+/// Placement of a grid of tiles on screen at a given [Scale].
+///
+/// Converts between tile positions and terminal cells, e.g. to find the tile
+/// under the mouse cursor.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct GridView {
+    /// Position of the top left cell of the grid.
+    pub origin: Position,
+    /// Size of the grid in tiles.
+    pub grid: Size,
+    pub scale: Scale,
+}
+
+impl GridView {
+    /// Area of the screen covered by the whole grid.
+    pub fn area(&self) -> Rect {
+        let size = self.scale.apply(self.grid);
+        Rect::new(self.origin.x, self.origin.y, size.width, size.height)
+    }
+
+    /// Area of the screen covered by the tile at the given position.
+    pub fn tile_area(&self, tile: TilePosition) -> Rect {
+        Rect::new(
+            self.origin.x + tile.x * self.scale.columns,
+            self.origin.y + tile.y * self.scale.rows,
+            self.scale.columns,
+            self.scale.rows,
+        )
+    }
+
+    /// Tile drawn at the given terminal cell, if any.
+    pub fn tile_at(&self, position: Position) -> Option<TilePosition> {
+        if !self.area().contains(position) {
+            return None;
+        }
+        Some(TilePosition::new(
+            (position.x - self.origin.x) / self.scale.columns,
+            (position.y - self.origin.y) / self.scale.rows,
+        ))
+    }
+}
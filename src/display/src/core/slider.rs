@@ -0,0 +1,121 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use crossterm::event::KeyCode;
+use data::core::actions::InterfaceAction;
+use data::core::colors;
+use data::core::widget_id::WidgetId;
+use ratatui::prelude::*;
+use typed_builder::TypedBuilder;
+
+use crate::core::render_context::RenderContext;
+
+/// Horizontal track for choosing a number within a range.
+///
+/// The value is set by clicking on or dragging along the track. When
+/// `focused`, the Left and Right keys adjust the value by `step` and Home and
+/// End select the minimum and maximum.
+#[derive(TypedBuilder)]
+#[builder(builder_method(name = new))]
+pub struct Slider {
+    id: WidgetId,
+    value: u32,
+    min: u32,
+    max: u32,
+    #[builder(default = 1)]
+    step: u32,
+    /// Produces the action for changing to a new value.
+    #[builder(setter(transform = |f: impl Fn(u32) -> InterfaceAction + 'static| Box::new(f) as Box<dyn Fn(u32) -> InterfaceAction>))]
+    on_change: Box<dyn Fn(u32) -> InterfaceAction>,
+    /// Produces the text displayed for a value, by default the number itself.
+    #[builder(default = Box::new(|value| value.to_string()), setter(transform = |f: impl Fn(u32) -> String + 'static| Box::new(f) as Box<dyn Fn(u32) -> String>))]
+    format: Box<dyn Fn(u32) -> String>,
+    /// Whether this slider receives keyboard input.
+    #[builder(default)]
+    focused: bool,
+}
+
+impl Slider {
+    /// Rounds `value` to the nearest step and clamps it to the slider's range.
+    ///
+    /// A range whose `min` exceeds its `max` always produces `max`.
+    fn snap(&self, value: f64) -> u32 {
+        let step = self.step.max(1) as f64;
+        let steps = ((value - self.min as f64) / step).round();
+        (self.min as f64 + steps * step).max(self.min as f64).min(self.max as f64) as u32
+    }
+}
+
+impl StatefulWidget for Slider {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let width = [self.min, self.max].map(|v| (self.format)(v).chars().count());
+        let label =
+            format!(" {:>width$}", (self.format)(self.value), width = width[0].max(width[1]));
+        let [track, value] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Length(label.chars().count() as u16),
+        ])
+        .areas(area);
+
+        context.register(self.id, area);
+        let hovered = context.hovered(self.id, area);
+        let pressed = context.mouse_down(self.id, track);
+        let range = self.max.saturating_sub(self.min) as f64;
+        let last = track.width.saturating_sub(1).max(1) as f64;
+
+        let mut new = self.value;
+        if let Some(position) = context.dragged(self.id, track) {
+            let fraction = (position.x.saturating_sub(track.x) as f64 / last).min(1.0);
+            new = self.snap(self.min as f64 + fraction * range);
+        }
+        if self.focused {
+            for code in context.key_presses() {
+                new = match code {
+                    KeyCode::Left => self.snap(new as f64 - self.step as f64),
+                    KeyCode::Right => self.snap(new as f64 + self.step as f64),
+                    KeyCode::Home => self.min,
+                    KeyCode::End => self.max,
+                    _ => new,
+                };
+            }
+        }
+        if new != self.value {
+            context.push_action((self.on_change)(new));
+        }
+
+        let thumb = if range > 0.0 {
+            ((self.value.saturating_sub(self.min)) as f64 / range * last).round() as u16
+        } else {
+            0
+        };
+        let thumb_style = if pressed || hovered {
+            Style::new().fg(colors::white()).bg(colors::selected())
+        } else {
+            Style::new().fg(colors::white())
+        };
+        for x in 0..track.width {
+            let (symbol, style) = match x.cmp(&thumb) {
+                Ordering::Less => ("━", Style::new().fg(colors::yellow())),
+                Ordering::Equal => ("●", thumb_style),
+                Ordering::Greater => ("─", Style::new().fg(colors::light_gray())),
+            };
+            buf.get_mut(track.x + x, track.y).set_symbol(symbol).set_style(style);
+        }
+        Line::from(label.fg(colors::white())).render(value, buf);
+    }
+}
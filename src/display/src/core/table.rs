@@ -0,0 +1,136 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::InterfaceAction;
+use data::core::colors;
+use data::core::widget_id::WidgetId;
+use ratatui::prelude::*;
+use ratatui::widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState};
+use typed_builder::TypedBuilder;
+
+use crate::core::render_context::RenderContext;
+
+/// Scrollable table of rows, one of which may be selected.
+///
+/// The table scrolls via the mouse wheel, and rows are selected by clicking
+/// them. When `focused`, the arrow keys, Page Up, Page Down, Home and End
+/// also change the selection, scrolling to keep it visible. A scrollbar is
+/// displayed when the rows do not fit. Each visible row is identified by
+/// `id.index(row)`.
+#[derive(TypedBuilder)]
+#[builder(builder_method(name = new))]
+pub struct Table {
+    id: WidgetId,
+    /// Column titles, displayed above the rows.
+    #[builder(default, setter(strip_option))]
+    header: Option<Vec<String>>,
+    widths: Vec<Constraint>,
+    rows: Vec<Vec<Line<'static>>>,
+    #[builder(default)]
+    selected: Option<usize>,
+    /// Produces the action for selecting the row at a given index.
+    #[builder(setter(transform = |f: impl Fn(usize) -> InterfaceAction + 'static| Box::new(f) as Box<dyn Fn(usize) -> InterfaceAction>))]
+    on_select: Box<dyn Fn(usize) -> InterfaceAction>,
+    /// Whether this table receives keyboard input.
+    #[builder(default)]
+    focused: bool,
+}
+
+impl StatefulWidget for Table {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        context.register(self.id, area);
+        let [header, body] = Layout::vertical([
+            Constraint::Length(if self.header.is_some() { 1 } else { 0 }),
+            Constraint::Fill(1),
+        ])
+        .areas(area);
+
+        let len = self.rows.len();
+        let visible = body.height as usize;
+        let max_offset = len.saturating_sub(visible);
+        let mut offset = context.scroll_offset(self.id).min(max_offset);
+        offset = offset.saturating_add_signed(context.scrolled(self.id, area)).min(max_offset);
+
+        if self.focused && len > 0 {
+            let page = visible.max(1);
+            let mut selected = self.selected;
+            for code in context.key_presses() {
+                let current = selected.unwrap_or(0);
+                selected = match code {
+                    KeyCode::Up if selected.is_some() => Some(current.saturating_sub(1)),
+                    KeyCode::Down if selected.is_some() => Some((current + 1).min(len - 1)),
+                    KeyCode::Up | KeyCode::Down | KeyCode::Home => Some(0),
+                    KeyCode::End => Some(len - 1),
+                    KeyCode::PageUp => Some(current.saturating_sub(page)),
+                    KeyCode::PageDown => Some((current + page).min(len - 1)),
+                    _ => selected,
+                };
+            }
+
+            if let Some(index) = selected.filter(|_| selected != self.selected) {
+                context.push_action((self.on_select)(index));
+                offset = offset.clamp((index + 1).saturating_sub(page), index).min(max_offset);
+            }
+        }
+        context.scroll_to(self.id, offset);
+
+        let [body, scrollbar] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Length(if max_offset > 0 { 1 } else { 0 }),
+        ])
+        .areas(body);
+        let [header, _] =
+            Layout::horizontal([Constraint::Length(body.width), Constraint::Fill(1)]).areas(header);
+
+        if let Some(titles) = self.header {
+            let cells = Layout::horizontal(self.widths.clone()).spacing(1).split(header);
+            for (title, cell) in titles.into_iter().zip(cells.iter()) {
+                Line::from(title.bold().fg(colors::light_gray())).render(*cell, buf);
+            }
+        }
+
+        for (i, row) in self.rows.into_iter().enumerate().skip(offset).take(visible) {
+            let row_area = Rect { y: body.y + (i - offset) as u16, height: 1, ..body };
+            let row_id = self.id.index(i);
+            context.register(row_id, row_area);
+            let hovered = context.hovered(row_id, row_area);
+            context.mouse_down(row_id, row_area);
+            context.clicked(row_id, row_area, (self.on_select)(i));
+
+            let cells = Layout::horizontal(self.widths.clone()).spacing(1).split(row_area);
+            for (line, cell) in row.into_iter().zip(cells.iter()) {
+                line.patch_style(Style::new().fg(colors::white())).render(*cell, buf);
+            }
+            if self.selected == Some(i) {
+                buf.set_style(row_area, Style::new().bg(colors::dark_blue()));
+            }
+            if hovered {
+                buf.set_style(row_area, Style::new().bg(colors::selected()));
+            }
+        }
+
+        if max_offset > 0 {
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .track_symbol(Some("│"))
+                .thumb_symbol("█")
+                .style(colors::light_gray())
+                .render(scrollbar, buf, &mut ScrollbarState::new(max_offset).position(offset));
+        }
+    }
+}
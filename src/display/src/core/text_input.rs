@@ -0,0 +1,262 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use data::core::actions::InterfaceAction;
+use data::core::colors;
+use data::core::widget_id::WidgetId;
+use ratatui::prelude::*;
+use typed_builder::TypedBuilder;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::core::render_context::RenderContext;
+
+/// Single-line editable text field.
+///
+/// The text being edited is stored in the [RenderContext] and can be read
+/// via [RenderContext::text_input]. When `focused`, the field receives
+/// keyboard input and pasted text, and other widgets stop receiving the keys
+/// it uses:
+///
+/// - Left/Right move the cursor, Home/End jump to the start or end, and holding
+///   Ctrl moves by a whole word.
+/// - Holding Shift while moving the cursor selects text, and Ctrl+A selects
+///   everything. Typing or pasting replaces the selection.
+/// - Backspace/Delete erase a character or the selection, or a whole word with
+///   Ctrl.
+/// - Enter produces the `on_submit` action.
+#[derive(TypedBuilder)]
+#[builder(builder_method(name = new))]
+pub struct TextInput {
+    id: WidgetId,
+    /// Text displayed while the field is empty.
+    #[builder(default, setter(into))]
+    placeholder: String,
+    /// Returns true if the given text is an acceptable value for this field.
+    /// Edits which would produce an unacceptable value are ignored.
+    #[builder(
+        default = Box::new(|_| true),
+        setter(transform = |f: impl Fn(&str) -> bool + 'static| Box::new(f) as Box<dyn Fn(&str) -> bool>)
+    )]
+    validate: Box<dyn Fn(&str) -> bool>,
+    /// Action produced when Enter is pressed.
+    #[builder(default, setter(strip_option, into))]
+    on_submit: Option<InterfaceAction>,
+    /// Whether this field receives keyboard input.
+    #[builder(default)]
+    focused: bool,
+}
+
+/// Editing state of a [TextInput], preserved between frames.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct TextInputState {
+    text: String,
+    /// Byte offset of the cursor within `text`.
+    cursor: usize,
+    /// Byte offset of the end of the selection opposite the cursor, if any.
+    anchor: Option<usize>,
+    /// Display column of `text` shown at the left edge of the field.
+    scroll: usize,
+}
+
+impl TextInputState {
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        Self { cursor: text.len(), text, anchor: None, scroll: 0 }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Byte range of the selected text, if any.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        let anchor = self.anchor.filter(|a| *a != self.cursor)?;
+        Some((anchor.min(self.cursor), anchor.max(self.cursor)))
+    }
+
+    fn handle_key(&mut self, key: KeyEvent, validate: &dyn Fn(&str) -> bool) {
+        let word = key.modifiers.contains(KeyModifiers::CONTROL);
+        let select = key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End => {
+                let target = match key.code {
+                    KeyCode::Left if word => self.previous_word(),
+                    KeyCode::Left => self.previous_grapheme(),
+                    KeyCode::Right if word => self.next_word(),
+                    KeyCode::Right => self.next_grapheme(),
+                    KeyCode::Home => 0,
+                    _ => self.text.len(),
+                };
+                self.move_cursor(target, select);
+            }
+            KeyCode::Char('a') if word => {
+                self.anchor = Some(0);
+                self.cursor = self.text.len();
+            }
+            KeyCode::Backspace | KeyCode::Delete => {
+                let (start, end) = self.selection().unwrap_or(match key.code {
+                    KeyCode::Backspace if word => (self.previous_word(), self.cursor),
+                    KeyCode::Backspace => (self.previous_grapheme(), self.cursor),
+                    _ if word => (self.cursor, self.next_word()),
+                    _ => (self.cursor, self.next_grapheme()),
+                });
+                self.replace(start, end, "", validate);
+            }
+            KeyCode::Char(c)
+                if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.insert(&c.to_string(), validate);
+            }
+            _ => {}
+        }
+    }
+
+    /// Replaces the selection, or inserts at the cursor if nothing is
+    /// selected. Line breaks are removed.
+    fn insert(&mut self, text: &str, validate: &dyn Fn(&str) -> bool) {
+        let text = text.replace(['\r', '\n'], "");
+        let (start, end) = self.selection().unwrap_or((self.cursor, self.cursor));
+        self.replace(start, end, &text, validate);
+    }
+
+    fn replace(&mut self, start: usize, end: usize, text: &str, validate: &dyn Fn(&str) -> bool) {
+        let mut result = self.text.clone();
+        result.replace_range(start..end, text);
+        if validate(&result) {
+            self.text = result;
+            self.cursor = start + text.len();
+            self.anchor = None;
+        }
+    }
+
+    fn move_cursor(&mut self, target: usize, select: bool) {
+        if select {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = target;
+    }
+
+    fn previous_grapheme(&self) -> usize {
+        self.text[..self.cursor].grapheme_indices(true).next_back().map_or(0, |(i, _)| i)
+    }
+
+    fn next_grapheme(&self) -> usize {
+        self.text[self.cursor..]
+            .graphemes(true)
+            .next()
+            .map_or(self.text.len(), |g| self.cursor + g.len())
+    }
+
+    /// Start of the word before the cursor.
+    fn previous_word(&self) -> usize {
+        self.text[..self.cursor]
+            .split_word_bound_indices()
+            .rev()
+            .find(|(_, w)| !w.trim().is_empty())
+            .map_or(0, |(i, _)| i)
+    }
+
+    /// End of the word after the cursor.
+    fn next_word(&self) -> usize {
+        self.text[self.cursor..]
+            .split_word_bound_indices()
+            .find(|(_, w)| !w.trim().is_empty())
+            .map_or(self.text.len(), |(i, w)| self.cursor + i + w.len())
+    }
+
+    /// Scrolls horizontally so that the cursor is visible in a field of the
+    /// given width.
+    fn scroll_to_cursor(&mut self, width: usize) {
+        let column = self.text[..self.cursor].width();
+        if column < self.scroll {
+            self.scroll = column;
+        } else if column >= self.scroll + width {
+            self.scroll = column + 1 - width;
+        }
+    }
+}
+
+impl StatefulWidget for TextInput {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        context.register(self.id, area);
+        let validate = self.validate;
+        let mut state = context.text_input_state(self.id);
+
+        if self.focused && context.receives_input() {
+            context.focus_text_input();
+            let mut submitted = false;
+            for event in context.events() {
+                match event {
+                    Event::Key(key) if key.kind == KeyEventKind::Press => {
+                        if key.code == KeyCode::Enter {
+                            submitted = true;
+                        } else {
+                            state.handle_key(*key, &validate);
+                        }
+                    }
+                    Event::Paste(text) => state.insert(text, &validate),
+                    _ => {}
+                }
+            }
+
+            if let (true, Some(action)) = (submitted, self.on_submit) {
+                context.push_action(action);
+            }
+        }
+
+        if area.is_empty() {
+            context.set_text_input(self.id, state);
+            return;
+        }
+
+        state.scroll_to_cursor(area.width as usize);
+        buf.set_style(area, Style::new().bg(colors::black()));
+        if state.text.is_empty() {
+            Line::from(self.placeholder.fg(colors::light_gray())).render(area, buf);
+        }
+
+        let selection = state.selection();
+        let mut column = 0;
+        for (i, grapheme) in state.text.grapheme_indices(true) {
+            let width = grapheme.width();
+            if column >= state.scroll {
+                let x = (column - state.scroll) as u16;
+                if x + width as u16 > area.width {
+                    break;
+                }
+                let mut style = Style::new().fg(colors::white());
+                if selection.is_some_and(|(start, end)| i >= start && i < end) {
+                    style = style.bg(colors::dark_blue());
+                }
+                buf.set_stringn(area.x + x, area.y, grapheme, width, style);
+            }
+            column += width;
+        }
+
+        if self.focused {
+            let x = state.text[..state.cursor].width().saturating_sub(state.scroll) as u16;
+            if x < area.width {
+                buf.get_mut(area.x + x, area.y).modifier.insert(Modifier::REVERSED);
+            }
+        }
+
+        context.set_text_input(self.id, state);
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use data::core::colors;
+use ratatui::layout::Position;
+use ratatui::prelude::*;
+use ratatui::symbols::border;
+use ratatui::widgets::{Block, Borders, Clear, Padding, Paragraph};
+
+/// Amount of time the mouse must remain over a widget before its tooltip is
+/// displayed.
+pub const TOOLTIP_DELAY: Duration = Duration::from_millis(500);
+
+/// Small floating window of text displayed on top of all other widgets.
+///
+/// Widgets request a tooltip via
+/// [RenderContext::hover_tooltip](crate::core::render_context::RenderContext::hover_tooltip),
+/// and it is drawn once the rest of the frame has been rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tooltip {
+    /// Contents of the tooltip. Unstyled text is displayed in white.
+    pub text: Text<'static>,
+    /// Position of the mouse cursor. The tooltip is placed below and to the
+    /// right of this position, moving to the other side of the cursor if it
+    /// would not fit within the render area.
+    pub position: Position,
+}
+
+impl Widget for Tooltip {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let width = (self.text.width() as u16 + 4).min(area.width);
+        let height = (self.text.height() as u16 + 2).min(area.height);
+
+        let x = if self.position.x + 1 + width <= area.right() {
+            self.position.x + 1
+        } else {
+            self.position.x.saturating_sub(width)
+        };
+        let y = if self.position.y + 1 + height <= area.bottom() {
+            self.position.y + 1
+        } else {
+            self.position.y.saturating_sub(height)
+        };
+        let rect = Rect::new(
+            x.clamp(area.x, area.right() - width),
+            y.clamp(area.y, area.bottom() - height),
+            width,
+            height,
+        );
+
+        Clear.render(rect, buf);
+        Paragraph::new(self.text)
+            .style(Style::new().fg(colors::white()))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(border::ROUNDED)
+                    .border_style(colors::light_gray())
+                    .padding(Padding::horizontal(1)),
+            )
+            .render(rect, buf);
+    }
+}
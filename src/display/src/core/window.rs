@@ -0,0 +1,35 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::colors;
+use ratatui::prelude::*;
+use ratatui::symbols::border;
+use ratatui::widgets::block::Title;
+use ratatui::widgets::{Block, Borders, Clear};
+
+/// Draws an opaque bordered window with the given title, erasing anything
+/// previously rendered in this area.
+///
+/// Returns the area inside the window's border.
+pub fn render(title: &str, area: Rect, buf: &mut Buffer) -> Rect {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(border::DOUBLE)
+        .border_style(colors::white())
+        .title(Title::from(format!(" {title} ").fg(colors::white())).alignment(Alignment::Center));
+    let inner = block.inner(area);
+    Clear.render(area, buf);
+    block.render(area, buf);
+    inner
+}
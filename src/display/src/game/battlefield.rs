@@ -0,0 +1,379 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Instant;
+
+use data::core::actions::{GameAction, InterfaceAction};
+use data::core::colors;
+use data::core::enemy::{Enemy, EnemyKind, EnemyTrait};
+use data::core::game_state::GameState;
+use data::core::interface_state::Tool;
+use data::core::map::{Terrain, TilePosition, MAP_HEIGHT, MAP_WIDTH, PATH_UNITS_PER_TILE};
+use data::core::projectile::EffectKind;
+use data::core::widget_id::{WidgetId, WidgetName};
+use ratatui::layout::{Position, Size};
+use ratatui::prelude::*;
+use rules::core::towers;
+
+use crate::core::animation::{Easing, Lerp, Sprite, Tween, FRAME_INTERVAL};
+use crate::core::bar;
+use crate::core::render_context::RenderContext;
+use crate::core::scale::{GridView, Scale};
+use crate::game::visual_effects::{
+    FloatingKind, FloatingNumber, VisualEffects, FLOAT_DURATION, FLOAT_RISE,
+};
+
+const ID: WidgetId = WidgetId::new(WidgetName::Battlefield);
+
+/// Minimum width of an enemy health bar, in cells.
+const HEALTH_BAR_WIDTH: u16 = 3;
+
+/// Draws the map and everything on it.
+///
+/// The map is drawn in layers, each on top of the previous one: terrain,
+/// the path, an optional range circle, towers, enemies, projectiles and
+/// effects, and finally optional overlays from [VisualEffects]. The tile under
+/// the mouse cursor is reported by [Self::hovered_tile]. While building, the
+/// hovered tile previews the new tower and clicking a tile builds it there.
+/// Otherwise, clicking a tower selects it.
+pub struct Battlefield<'a> {
+    pub state: &'a GameState,
+
+    /// Decorations observed from `state`, see [VisualEffects::observe].
+    pub effects: &'a VisualEffects,
+    pub scale: Scale,
+
+    /// Outline the attack range of the tower being built, or else of the
+    /// selected or hovered tower.
+    pub range_circles: bool,
+
+    /// Draw health bars above damaged enemies.
+    pub health_bars: bool,
+
+    /// Draw floating numbers for damage dealt and gold earned.
+    pub damage_numbers: bool,
+
+    /// Effect of clicking on the map.
+    pub tool: Tool,
+
+    /// Position of the tower whose details are displayed, if any.
+    pub selected_tower: Option<TilePosition>,
+}
+
+impl<'a> Battlefield<'a> {
+    /// Map tile which the mouse is currently over when the battlefield is
+    /// rendered in `area`, if any.
+    pub fn hovered_tile(&self, area: Rect, context: &RenderContext) -> Option<TilePosition> {
+        let hovered = context.current_hover() == Some(ID);
+        context.mouse_position().filter(|_| hovered).and_then(|p| self.view(area).tile_at(p))
+    }
+
+    /// Center and radius of the attack range to outline, given the tile
+    /// under the mouse cursor.
+    fn range_circle(&self, hovered: Option<TilePosition>) -> Option<(TilePosition, u32)> {
+        if let Tool::Build(kind) = self.tool {
+            return hovered.map(|tile| (tile, kind.definition().stats.range));
+        }
+        self.selected_tower
+            .or(hovered)
+            .and_then(|p| towers::at(self.state, p))
+            .map(|tower| (tower.position, tower.stats().range))
+    }
+
+    fn view(&self, area: Rect) -> GridView {
+        GridView {
+            origin: area.as_position(),
+            grid: Size { width: MAP_WIDTH, height: MAP_HEIGHT },
+            scale: self.scale,
+        }
+    }
+}
+
+impl<'a> StatefulWidget for Battlefield<'a> {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let view = self.view(area);
+        let map_area = view.area().intersection(area);
+        context.register(ID, map_area);
+        context.mouse_down(ID, map_area);
+        context.hovered(ID, map_area);
+        let tile = self.hovered_tile(area, context);
+        for position in context.click_positions(ID, map_area) {
+            let Some(target) = view.tile_at(position) else {
+                continue;
+            };
+            match self.tool {
+                Tool::Build(kind) => {
+                    context.push_action(GameAction::BuildTower(kind, target));
+                }
+                Tool::Select => {
+                    let tower = towers::at(self.state, target).map(|t| t.position);
+                    context.push_action(InterfaceAction::SelectTower(tower));
+                }
+            }
+        }
+
+        let state = self.state;
+        for y in 0..MAP_HEIGHT {
+            for x in 0..MAP_WIDTH {
+                let position = TilePosition::new(x, y);
+                let tile = if state.map.is_path(position) {
+                    Glyph { symbol: "·", fg: colors::peach(), bg: colors::brown() }
+                } else {
+                    terrain_glyph(state.map.terrain(position))
+                };
+                fill(&view, position, tile, buf);
+            }
+        }
+
+        if self.range_circles {
+            if let Some((center, range)) = self.range_circle(tile) {
+                range_circle(&view, center, range, area, buf);
+            }
+        }
+
+        if let Some(selected) = self.selected_tower {
+            buf.set_style(
+                view.tile_area(selected).intersection(area),
+                Style::new().bg(colors::dark_blue()),
+            );
+        }
+        for tower in &state.towers {
+            let style = if tower.is_disabled(state.tick) {
+                Style::new().fg(colors::dark_gray())
+            } else {
+                Style::new().fg(level_color(tower.level())).bold()
+            };
+            draw(&view, tower.position, tower.kind.definition().glyph, style, buf);
+        }
+
+        let now = context.now();
+        let progress = context.tick_progress();
+        let mut enemies = vec![];
+        for enemy in &state.enemies {
+            let distance = self
+                .effects
+                .interpolated_distance(enemy.id, progress)
+                .unwrap_or(enemy.distance as f64);
+            if let Some(cell) = enemy_cell(&view, state, enemy, distance) {
+                if buf.area.contains(cell) {
+                    buf.get_mut(cell.x, cell.y)
+                        .set_symbol(walk_cycle(enemy.kind).frame(distance))
+                        .set_style(Style::new().fg(health_color(enemy)));
+                }
+                enemies.push((enemy, cell));
+            }
+        }
+        if progress < 1.0 && !state.enemies.is_empty() {
+            context.request_redraw(now + FRAME_INTERVAL);
+        }
+
+        for projectile in &state.projectiles {
+            draw(&view, projectile.position, "*", Style::new().fg(colors::white()), buf);
+        }
+
+        for effect in &state.effects {
+            let (symbol, color) = match effect.kind {
+                EffectKind::Explosion => ("✶", colors::orange()),
+                EffectKind::Frost => ("❄", colors::cyan()),
+            };
+            draw(&view, effect.position, symbol, Style::new().fg(color), buf);
+        }
+
+        if self.health_bars {
+            for (enemy, cell) in enemies.iter().filter(|(e, _)| e.health < e.max_health) {
+                health_bar(&view, *cell, enemy, area, buf);
+            }
+        }
+
+        if self.damage_numbers {
+            let floating = self.effects.floating();
+            for number in floating {
+                floating_number(&view, number, now, area, buf);
+            }
+            if !floating.is_empty() {
+                context.request_redraw(now + FRAME_INTERVAL);
+            }
+        }
+
+        if let Some(tile) = tile {
+            let highlight = view.tile_area(tile).intersection(area);
+            if let Tool::Build(kind) = self.tool {
+                let valid = towers::can_build(state, tile) && towers::can_afford(state, kind);
+                let color = if valid { colors::dark_green() } else { colors::dark_red() };
+                draw(&view, tile, kind.definition().glyph, Style::new().fg(colors::white()), buf);
+                buf.set_style(highlight, Style::new().bg(color));
+            } else {
+                buf.set_style(highlight, Style::new().bg(colors::selected()));
+            }
+        }
+    }
+}
+
+/// Highlights the tiles at the edge of the area within `range` of `center`,
+/// i.e. those with a neighbor which is out of range.
+fn range_circle(view: &GridView, center: TilePosition, range: u32, area: Rect, buf: &mut Buffer) {
+    let in_range = |x: i32, y: i32| {
+        x >= 0
+            && y >= 0
+            && towers::within_range(center, TilePosition::new(x as u16, y as u16), range)
+    };
+    for y in 0..MAP_HEIGHT {
+        for x in 0..MAP_WIDTH {
+            let (x, y) = (x as i32, y as i32);
+            if in_range(x, y)
+                && [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+                    .into_iter()
+                    .any(|(x, y)| !in_range(x, y))
+            {
+                let tile = view.tile_area(TilePosition::new(x as u16, y as u16));
+                buf.set_style(tile.intersection(area), Style::new().bg(colors::blue_gray()));
+            }
+        }
+    }
+}
+
+/// Appearance of a tile, repeated across every cell it occupies.
+struct Glyph {
+    symbol: &'static str,
+    fg: Color,
+    bg: Color,
+}
+
+fn terrain_glyph(terrain: Terrain) -> Glyph {
+    match terrain {
+        Terrain::Grass => Glyph { symbol: " ", fg: colors::dark_green(), bg: colors::black() },
+        Terrain::Forest => Glyph { symbol: "♣", fg: colors::dark_green(), bg: colors::black() },
+        Terrain::Water => Glyph { symbol: "~", fg: colors::light_blue(), bg: colors::dark_blue() },
+        Terrain::Rock => Glyph { symbol: "▲", fg: colors::blue_gray(), bg: colors::black() },
+    }
+}
+
+/// Walk cycle for an enemy, advancing by distance travelled so that it stops
+/// while the game is paused.
+fn walk_cycle(kind: EnemyKind) -> Sprite {
+    let frames: &'static [&'static str] = match kind {
+        EnemyKind::Grunt => &["g", "q"],
+        EnemyKind::Runner => &["r", "ɹ"],
+        EnemyKind::Brute => &["B", "ß"],
+        EnemyKind::Bat => &["v", "^"],
+        EnemyKind::Warlord => &["W", "M"],
+    };
+    Sprite { frames, frame_length: (PATH_UNITS_PER_TILE / 2) as f64 }
+}
+
+/// Cell at which to draw an enemy which has travelled `distance` units along
+/// its route, moving smoothly between tile centers when tiles span multiple
+/// cells.
+fn enemy_cell(
+    view: &GridView,
+    state: &GameState,
+    enemy: &Enemy,
+    distance: f64,
+) -> Option<Position> {
+    let flying = enemy.has_trait(EnemyTrait::Flying);
+    let from = state.map.enemy_position(distance as u32, flying)?;
+    let to =
+        state.map.enemy_position(distance as u32 + PATH_UNITS_PER_TILE, flying).unwrap_or(from);
+    let t = (distance % PATH_UNITS_PER_TILE as f64) / PATH_UNITS_PER_TILE as f64;
+    Some(center(view, from).lerp(center(view, to), t))
+}
+
+/// Cell at which a tile's symbol is drawn.
+fn center(view: &GridView, position: TilePosition) -> Position {
+    let area = view.tile_area(position);
+    Position::new(area.x + (area.width - 1) / 2, area.y + (area.height - 1) / 2)
+}
+
+/// Color indicating how many times a tower has been upgraded.
+fn level_color(level: usize) -> Color {
+    match level {
+        0 | 1 => colors::white(),
+        2 => colors::light_green(),
+        3 => colors::cyan(),
+        _ => colors::yellow(),
+    }
+}
+
+/// Color indicating how much health an enemy has remaining.
+fn health_color(enemy: &Enemy) -> Color {
+    let fraction = enemy.health_fraction();
+    if fraction > 0.66 {
+        colors::light_green()
+    } else if fraction > 0.33 {
+        colors::orange()
+    } else {
+        colors::red()
+    }
+}
+
+/// Fills every cell of a tile with the given [Glyph].
+fn fill(view: &GridView, position: TilePosition, glyph: Glyph, buf: &mut Buffer) {
+    let area = view.tile_area(position).intersection(buf.area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            buf.get_mut(x, y).set_symbol(glyph.symbol).set_fg(glyph.fg).set_bg(glyph.bg);
+        }
+    }
+}
+
+/// Draws a symbol in the center of a tile, keeping its background color.
+fn draw(view: &GridView, position: TilePosition, symbol: &str, style: Style, buf: &mut Buffer) {
+    let cell = center(view, position);
+    if buf.area.contains(cell) {
+        buf.get_mut(cell.x, cell.y).set_symbol(symbol).set_style(style);
+    }
+}
+
+/// Draws a bar showing an enemy's remaining health centered on the row above
+/// the enemy's tile, or below it if the tile is on the top row.
+fn health_bar(view: &GridView, cell: Position, enemy: &Enemy, area: Rect, buf: &mut Buffer) {
+    let width = view.scale.columns.max(HEALTH_BAR_WIDTH);
+    let x = cell.x.saturating_sub((width - 1) / 2).max(area.x);
+    let top = cell.y - (view.scale.rows - 1) / 2;
+    let y = if top > area.y { top - 1 } else { top + view.scale.rows };
+    let bar = Rect::new(x, y, width, 1).intersection(area);
+    let style = Style::new().fg(health_color(enemy)).bg(colors::dark_gray());
+    bar::render(enemy.health_fraction(), bar, style, buf);
+}
+
+/// Draws a [FloatingNumber] rising from the top of its tile and fading out.
+fn floating_number(
+    view: &GridView,
+    number: &FloatingNumber,
+    now: Instant,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let tile = view.tile_area(number.position);
+    let rise = Tween::new(0, FLOAT_RISE, number.start, FLOAT_DURATION).easing(Easing::EaseOut);
+    let Some(y) = tile.y.checked_sub(rise.value(now)) else {
+        return;
+    };
+    if y < area.y || y >= area.bottom() {
+        return;
+    }
+
+    let text = number.text();
+    let width = text.chars().count() as u16;
+    let x = (tile.x + tile.width / 2).saturating_sub(width / 2).max(area.x);
+    let (from, to) = match number.kind {
+        FloatingKind::Damage => (colors::white(), colors::dark_gray()),
+        FloatingKind::Gold => (colors::yellow(), colors::brown()),
+    };
+    let fade = Tween::new(from, to, number.start, FLOAT_DURATION).easing(Easing::EaseIn);
+    let style = Style::new().fg(fade.value(now)).bold();
+    buf.set_stringn(x, y, text, area.right().saturating_sub(x) as usize, style);
+}
@@ -0,0 +1,183 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::GameAction;
+use data::core::colors;
+use data::core::enemy::Enemy;
+use data::core::game_state::{GameSpeed, GameState};
+use data::core::widget_id::{WidgetId, WidgetName};
+use ratatui::prelude::*;
+use rules::core::waves;
+
+use crate::core::bar;
+use crate::core::button::{Button, ButtonSize};
+use crate::core::layout::Insets;
+use crate::core::render_context::RenderContext;
+
+const ID: WidgetId = WidgetId::new(WidgetName::Hud);
+
+/// Label of the button which starts the next wave early.
+const SEND_EARLY_LABEL: &str = "Send";
+
+/// Key which starts the next wave early.
+pub const SEND_EARLY_HOTKEY: KeyCode = KeyCode::Char('n');
+
+/// Narrowest boss health bar worth drawing, in cells.
+const MIN_BOSS_BAR_WIDTH: u16 = 6;
+
+/// Single row at the top of the game area summarizing the player's
+/// resources and progress.
+///
+/// Values briefly flash when they change, green when they go up and red
+/// when they go down. The next wave can be sent early from here for bonus
+/// gold. While a boss is on the map, its health is shown in
+/// the space between the resources and the game speed.
+pub struct Hud<'a> {
+    pub state: &'a GameState,
+
+    /// Whether the simulation is currently paused.
+    pub paused: bool,
+}
+
+impl<'a> StatefulWidget for Hud<'a> {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let state = self.state;
+        let mut spans = vec![" ".into()];
+        spans.extend(value(
+            context,
+            WidgetName::Gold,
+            "◆ ".fg(colors::yellow()),
+            state.gold,
+            colors::yellow(),
+        ));
+        spans.push("  ".into());
+        spans.extend(value(
+            context,
+            WidgetName::Lives,
+            "♥ ".fg(colors::red()),
+            state.lives,
+            colors::white(),
+        ));
+        spans.push("  ".into());
+        spans.extend(value(context, WidgetName::Wave, label("Wave "), state.wave, colors::white()));
+        spans.push(format!("/{}", state.total_waves()).fg(colors::white()));
+        if let Some(remaining) = state.time_until_next_wave() {
+            let seconds = remaining.as_secs_f64().ceil();
+            spans.push(label("  Next "));
+            spans.push(format!("{seconds}s").fg(colors::white()));
+        }
+        let mut score = vec!["  ".into()];
+        score.extend(value(
+            context,
+            WidgetName::Score,
+            label("Score "),
+            state.score,
+            colors::white(),
+        ));
+
+        let speed = if self.paused {
+            "Paused".fg(colors::yellow()).bold()
+        } else {
+            speed_label(state.speed).fg(colors::light_green())
+        };
+        let resources = Line::from(spans);
+        let score = Line::from(score);
+        let bonus = waves::early_bonus(state).map(|bonus| format!("+{bonus}g"));
+        let send_width = bonus.as_ref().map_or(0, |b| SEND_EARLY_LABEL.len() + b.len() + 6);
+        let [left, send, score_area, middle, right] = Layout::horizontal([
+            Constraint::Length(resources.width() as u16),
+            Constraint::Length(send_width as u16),
+            Constraint::Length(score.width() as u16),
+            Constraint::Fill(1),
+            Constraint::Length(speed.width() as u16 + 1),
+        ])
+        .areas(area);
+        resources.render(left, buf);
+        score.render(score_area, buf);
+        if let Some(bonus) = bonus {
+            Button::new()
+                .label(SEND_EARLY_LABEL)
+                .action(GameAction::SendWaveEarly)
+                .id(ID.child(WidgetName::SendEarly))
+                .hotkey(SEND_EARLY_HOTKEY)
+                .secondary(bonus)
+                .size(ButtonSize::Small)
+                .build()
+                .render(
+                    context.inset(send, Insets { left: 1, ..Default::default() }),
+                    buf,
+                    context,
+                );
+        }
+        if let Some(boss) = state.boss() {
+            boss_bar(boss, context.inset(middle, Insets::symmetric(2, 0)), buf);
+        }
+        Line::from(speed).render(right, buf);
+    }
+}
+
+/// Draws the name, phase and remaining health of a boss, omitting the bar if
+/// there is not enough room for it.
+fn boss_bar(boss: &Enemy, area: Rect, buf: &mut Buffer) {
+    let definition = boss.definition();
+    let title = Line::from(vec![
+        definition.glyph.fg(colors::red()).bold(),
+        format!(" {} ", definition.name).fg(colors::white()),
+        format!("P{} ", boss.phase + 1).fg(colors::orange()),
+    ]);
+    let width = title.width() as u16;
+    let [title_area, bar_area] =
+        Layout::horizontal([Constraint::Length(width), Constraint::Fill(1)]).areas(area);
+    if bar_area.width < MIN_BOSS_BAR_WIDTH {
+        return;
+    }
+    title.render(title_area, buf);
+    bar::render(
+        boss.health_fraction(),
+        bar_area,
+        Style::new().fg(colors::red()).bg(colors::dark_gray()),
+        buf,
+    );
+}
+
+/// Label and value for one resource, flashing if the value has changed.
+fn value(
+    context: &mut RenderContext,
+    name: WidgetName,
+    label: Span<'static>,
+    value: u32,
+    color: Color,
+) -> [Span<'static>; 2] {
+    let mut style = Style::new().fg(color);
+    if let Some(flash) = context.flash_on_change(ID.child(name), value as i64) {
+        let highlight = if flash.increased { colors::light_green() } else { colors::red() };
+        style = style.fg(flash.color(highlight, color, context.now())).bold();
+    }
+    [label, Span::styled(value.to_string(), style)]
+}
+
+fn label(text: &'static str) -> Span<'static> {
+    text.fg(colors::light_gray())
+}
+
+fn speed_label(speed: GameSpeed) -> &'static str {
+    match speed {
+        GameSpeed::Normal => "▶ 1x",
+        GameSpeed::Fast => "▶▶ 2x",
+        GameSpeed::Fastest => "▶▶▶ 4x",
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::actions::InterfaceAction;
+use data::core::colors;
+use data::core::game_state::GameState;
+use data::core::interface_state::Tool;
+use data::core::widget_id::{WidgetId, WidgetName};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders};
+use rules::core::towers;
+
+use crate::core::button::{Button, ButtonSize};
+use crate::core::render_context::RenderContext;
+
+const ID: WidgetId = WidgetId::new(WidgetName::TowerShop);
+
+/// Height of each entry in the shop, in rows.
+const ENTRY_HEIGHT: u16 = 4;
+
+/// Panel listing the towers which the player can build.
+///
+/// Selecting an entry, by clicking it or pressing its hotkey, switches to
+/// [Tool::Build] for that tower. Selecting the active entry again switches
+/// back to [Tool::Select].
+pub struct Shop<'a> {
+    pub state: &'a GameState,
+    pub tool: Tool,
+}
+
+impl<'a> StatefulWidget for Shop<'a> {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let block = Block::default().borders(Borders::LEFT);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [title, entries] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(inner);
+        Line::from(" Towers".fg(colors::white()).bold()).render(title, buf);
+
+        let towers = &self.state.unlocked_towers;
+        let rows = towers.len().min((entries.height / ENTRY_HEIGHT) as usize) as u16;
+        let entries = Rect { height: rows * ENTRY_HEIGHT, ..entries };
+        let cells = context.grid(entries, 1, rows, 0);
+        for (i, (kind, entry)) in towers.iter().copied().zip(cells).enumerate() {
+            let definition = kind.definition();
+            let selected = self.tool == Tool::Build(kind);
+            let action = if selected { Tool::Select } else { Tool::Build(kind) };
+            let button = Button::new()
+                .label(definition.name)
+                .icon(definition.glyph.fg(colors::cyan()))
+                .action(InterfaceAction::SetTool(action))
+                .id(ID.index(i))
+                .hotkey(definition.hotkey)
+                .secondary(format!("{}g", definition.cost))
+                .selected(selected)
+                .size(ButtonSize::Medium);
+            if towers::can_afford(self.state, kind) {
+                button.build().render(entry, buf, context);
+            } else {
+                button.disabled("Not enough gold").build().render(entry, buf, context);
+            }
+        }
+    }
+}
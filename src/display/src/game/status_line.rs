@@ -0,0 +1,97 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::colors;
+use data::core::game_state::GameState;
+use data::core::interface_state::Tool;
+use data::core::map::TilePosition;
+use data::core::settings::KeyBindings;
+use ratatui::prelude::*;
+use rules::core::towers;
+
+use crate::core::keys;
+
+/// Line at the bottom of the game area describing what clicking the map does
+/// and listing the main hotkeys.
+pub struct StatusLine<'a> {
+    pub state: &'a GameState,
+    pub tool: Tool,
+    pub selected_tower: Option<TilePosition>,
+
+    /// Map tile under the mouse cursor, if any.
+    pub hovered_tile: Option<TilePosition>,
+    pub key_bindings: KeyBindings,
+}
+
+impl<'a> Widget for StatusLine<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let hint = self.hint();
+        let bindings = self.key_bindings;
+        let escape = if self.tool != Tool::Select {
+            "Cancel"
+        } else if self.selected_tower.is_some() {
+            "Deselect"
+        } else {
+            "Menu"
+        };
+        let mut hotkeys = vec![];
+        for (code, label) in [
+            (KeyCode::Esc, escape),
+            (bindings.pause, "Pause"),
+            (bindings.change_speed, "Speed"),
+            (bindings.quit, "Quit"),
+        ] {
+            hotkeys.push(format!("[{}] ", keys::name(code)).fg(colors::light_gray()));
+            hotkeys.push(format!("{label} ").fg(colors::white()));
+        }
+        let hotkeys = Line::from(hotkeys);
+
+        let [hint_area, keys_area] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Length(hotkeys.width() as u16)])
+                .areas(area);
+        Line::from(vec![" ".into(), hint]).render(hint_area, buf);
+        hotkeys.render(keys_area, buf);
+    }
+}
+
+impl<'a> StatusLine<'a> {
+    /// Describes what clicking the hovered tile would do.
+    fn hint(&self) -> Span<'static> {
+        let state = self.state;
+        let hovered_tower = self.hovered_tile.and_then(|p| towers::at(state, p));
+        match self.tool {
+            Tool::Build(kind) => {
+                let definition = kind.definition();
+                if self.hovered_tile.is_some_and(|p| !towers::can_build(state, p)) {
+                    "Cannot build here".fg(colors::red())
+                } else if !towers::can_afford(state, kind) {
+                    format!("Not enough gold for {}", definition.name).fg(colors::red())
+                } else {
+                    format!("Click a tile to build {} for {}g", definition.name, definition.cost)
+                        .fg(colors::white())
+                }
+            }
+            Tool::Select => match hovered_tower {
+                Some(tower) => {
+                    format!("Click to inspect {}", tower.definition().name).fg(colors::white())
+                }
+                None if self.selected_tower.is_some() => {
+                    "Upgrade or sell the selected tower".fg(colors::light_gray())
+                }
+                None => "Pick a tower from the shop to build".fg(colors::light_gray()),
+            },
+        }
+    }
+}
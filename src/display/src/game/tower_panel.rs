@@ -0,0 +1,244 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::{GameAction, InterfaceAction};
+use data::core::colors;
+use data::core::game_state::{GameState, TICK_DURATION};
+use data::core::interface_state::{Confirmation, Modal};
+use data::core::tower::{TargetingMode, Tower};
+use data::core::upgrades::{TowerStats, MAX_BRANCHES};
+use data::core::widget_id::{WidgetId, WidgetName};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders};
+use rules::core::towers;
+
+use crate::core::button::{Button, ButtonSize};
+use crate::core::layout::{Edge, Insets};
+use crate::core::radio_group::RadioGroup;
+use crate::core::render_context::RenderContext;
+
+const ID: WidgetId = WidgetId::new(WidgetName::TowerPanel);
+
+/// Height of the targeting, sell and close controls at the bottom of the
+/// panel, in rows.
+const CONTROLS_HEIGHT: u16 = 8;
+
+/// Panel displaying details of a placed tower and options to upgrade it,
+/// sell it, or change its targeting.
+pub struct TowerPanel<'a> {
+    pub state: &'a GameState,
+    pub tower: &'a Tower,
+}
+
+impl<'a> StatefulWidget for TowerPanel<'a> {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let block = Block::default().borders(Borders::LEFT);
+        let inner = block.inner(area);
+        block.render(area, buf);
+        let (top, rest) = context.anchor(inner, Edge::Top, TowerSummary::HEIGHT + 1);
+        let (bottom, upgrades) = context.anchor(rest, Edge::Bottom, CONTROLS_HEIGHT);
+        let tower = self.tower;
+        let position = tower.position;
+
+        TowerSummary { tower }.render(top, buf);
+        self.render_upgrades(upgrades, buf, context);
+
+        let [target, modes, _, sell, close] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(4),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(bottom);
+        Line::from(label(" Target")).render(target, buf);
+        let all = enum_iterator::all::<TargetingMode>().collect::<Vec<_>>();
+        let selected = all.iter().position(|m| *m == tower.targeting).unwrap_or_default();
+        RadioGroup::new()
+            .id(ID.child(WidgetName::Targeting))
+            .options(all.iter().map(|m| targeting_name(*m).to_string()).collect())
+            .selected(selected)
+            .on_select(move |i| GameAction::SetTargeting(position, all[i]).into())
+            .build()
+            .render(context.inset(modes, Insets { left: 1, ..Default::default() }), buf, context);
+
+        Button::new()
+            .label("Sell")
+            .action(InterfaceAction::ShowModal(Modal::Confirm(Confirmation::SellTower(position))))
+            .id(ID.child(WidgetName::Sell))
+            .hotkey(SELL_HOTKEY)
+            .secondary(format!("+{}g", towers::sell_value(tower)))
+            .size(ButtonSize::Small)
+            .build()
+            .render(sell, buf, context);
+        Button::new()
+            .label("Close")
+            .action(InterfaceAction::SelectTower(None))
+            .id(ID.child(WidgetName::Close))
+            .size(ButtonSize::Small)
+            .build()
+            .render(close, buf, context);
+    }
+}
+
+/// Name, level, record and current stats of a tower, taking up
+/// [TowerSummary::HEIGHT] rows.
+pub struct TowerSummary<'a> {
+    pub tower: &'a Tower,
+}
+
+impl<'a> TowerSummary<'a> {
+    pub const HEIGHT: u16 = 7;
+}
+
+impl<'a> Widget for TowerSummary<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let tower = self.tower;
+        let [title, kills, _, stats] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(4),
+        ])
+        .areas(area);
+        let definition = tower.definition();
+        Line::from(vec![
+            format!(" {} {}  ", definition.glyph, definition.name).fg(colors::white()).bold(),
+            format!("Lv {}", tower.level()).fg(colors::yellow()),
+        ])
+        .render(title, buf);
+        Line::from(vec![
+            label(" Kills "),
+            tower.kills.to_string().fg(colors::white()),
+            label("  Dmg "),
+            tower.damage_dealt.to_string().fg(colors::white()),
+        ])
+        .render(kills, buf);
+        render_stats(tower.stats(), stats, buf);
+    }
+}
+
+impl<'a> TowerPanel<'a> {
+    /// Draws a button for each upgrade which can be purchased next, followed
+    /// by the changes it would make to the tower's stats.
+    fn render_upgrades(&self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let tower = self.tower;
+        let available = tower.available_upgrades();
+        if available.is_empty() {
+            Line::from(label(" Fully upgraded")).render(area, buf);
+            return;
+        }
+
+        let mut rest = area;
+        let current = tower.stats();
+        let nodes = tower.definition().upgrades.nodes;
+        for (&index, hotkey) in available.iter().zip(UPGRADE_HOTKEYS) {
+            let node = &nodes[index];
+            let (row, remaining) = context.anchor(rest, Edge::Top, 1);
+            let button = Button::new()
+                .label(node.name)
+                .action(GameAction::UpgradeTower(tower.position, index))
+                .id(ID.child(WidgetName::Upgrade).index(index))
+                .hotkey(hotkey)
+                .secondary(format!("{}g", node.cost))
+                .size(ButtonSize::Small);
+            if towers::can_upgrade(self.state, tower, index) {
+                button.build().render(row, buf, context);
+            } else {
+                button.disabled("Not enough gold").build().render(row, buf, context);
+            }
+
+            let lines = changes(current, current.apply(node.modifiers), remaining.width);
+            let (changes, remaining) = context.anchor(remaining, Edge::Top, lines.len() as u16);
+            Text::from(lines).render(changes, buf);
+            rest = remaining;
+        }
+    }
+}
+
+/// Key which sells the tower.
+pub const SELL_HOTKEY: KeyCode = KeyCode::Char('s');
+
+/// Keys for purchasing each of the available upgrades, in order. Upgrade
+/// trees are validated to never offer more choices than there are keys.
+pub const UPGRADE_HOTKEYS: [KeyCode; MAX_BRANCHES] = [KeyCode::Char('u'), KeyCode::Char('i')];
+
+/// Draws one row per stat.
+fn render_stats(stats: TowerStats, area: Rect, buf: &mut Buffer) {
+    let rows = [
+        ("Damage", stats.damage.to_string()),
+        ("Range", stats.range.to_string()),
+        ("Rate/s", fire_rate(stats)),
+        ("Targets", stats.targets.to_string()),
+    ];
+    let lines = Layout::vertical([Constraint::Length(1); 4]).split(area);
+    for ((name, value), line) in rows.into_iter().zip(lines.iter()) {
+        Line::from(vec![
+            label(" "),
+            label(name),
+            " ".repeat(8 - name.len()).into(),
+            value.fg(colors::white()),
+        ])
+        .render(*line, buf);
+    }
+}
+
+/// Describes each stat which differs between `before` and `after`, e.g.
+/// "Dmg 8→10", packed into lines no wider than `width`.
+fn changes(before: TowerStats, after: TowerStats, width: u16) -> Vec<Line<'static>> {
+    let stats = [
+        ("Dmg", before.damage.to_string(), after.damage.to_string()),
+        ("Rng", before.range.to_string(), after.range.to_string()),
+        ("Rate", fire_rate(before), fire_rate(after)),
+        ("Tgt", before.targets.to_string(), after.targets.to_string()),
+    ];
+    let mut lines = vec![];
+    let mut line = Line::from("  ");
+    for (name, before, after) in stats.into_iter().filter(|(_, b, a)| a != b) {
+        let text = format!("{name} {before}→{after}");
+        if line.width() > 2 && line.width() + 1 + text.chars().count() > width as usize {
+            lines.push(std::mem::replace(&mut line, Line::from("  ")));
+        }
+        if line.width() > 2 {
+            line.spans.push(" ".into());
+        }
+        line.spans.push(label(name));
+        line.spans.push(format!(" {before}→").fg(colors::white()));
+        line.spans.push(after.fg(colors::light_green()));
+    }
+    lines.push(line);
+    lines
+}
+
+/// Shots per second fired by a tower with the given stats.
+fn fire_rate(stats: TowerStats) -> String {
+    let seconds = (TICK_DURATION * stats.reload.max(1)).as_secs_f64();
+    format!("{:.1}", 1.0 / seconds)
+}
+
+fn label(text: &'static str) -> Span<'static> {
+    text.fg(colors::light_gray())
+}
+
+pub fn targeting_name(mode: TargetingMode) -> &'static str {
+    match mode {
+        TargetingMode::First => "First",
+        TargetingMode::Last => "Last",
+        TargetingMode::Strongest => "Strongest",
+        TargetingMode::Weakest => "Weakest",
+    }
+}
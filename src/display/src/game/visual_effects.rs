@@ -0,0 +1,145 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use data::core::enemy::EnemyId;
+use data::core::game_event::GameEvent;
+use data::core::game_state::GameState;
+use data::core::map::TilePosition;
+
+use crate::core::animation::Lerp;
+
+/// Amount of time a floating number remains on screen.
+pub const FLOAT_DURATION: Duration = Duration::from_millis(900);
+
+/// Number of rows a floating number rises over its lifetime.
+pub const FLOAT_RISE: u16 = 2;
+
+/// Short-lived decorations drawn on top of the battlefield.
+///
+/// Floating numbers are started from the [GameState::events] produced by the
+/// simulation. Enemy movement is smoothed by comparing successive game
+/// states, so that purely visual details never affect [GameState] or its
+/// determinism.
+#[derive(Debug, Default)]
+pub struct VisualEffects {
+    tick: u64,
+
+    /// Sequence number of the first game event not yet displayed.
+    next_event: u64,
+    enemies: HashMap<EnemyId, SeenEnemy>,
+    floating: Vec<FloatingNumber>,
+}
+
+/// Last observed state of an enemy.
+#[derive(Debug, Copy, Clone)]
+struct SeenEnemy {
+    distance: u32,
+
+    /// Distance along the path before the most recent tick.
+    previous_distance: u32,
+}
+
+/// Number which rises from a map tile and fades out.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FloatingNumber {
+    pub kind: FloatingKind,
+    pub amount: u32,
+    pub position: TilePosition,
+    pub start: Instant,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FloatingKind {
+    /// Damage dealt to an enemy.
+    Damage,
+    /// Gold earned by defeating an enemy or selling a tower.
+    Gold,
+}
+
+impl FloatingNumber {
+    pub fn text(&self) -> String {
+        match self.kind {
+            FloatingKind::Damage => format!("-{}", self.amount),
+            FloatingKind::Gold => format!("+{}g", self.amount),
+        }
+    }
+}
+
+impl VisualEffects {
+    /// Starts effects for game events which have not been displayed yet
+    /// and records enemy positions for [Self::interpolated_distance].
+    /// Should be called once per frame.
+    ///
+    /// Each hit on an enemy displays a damage number and each gold reward
+    /// displays the amount earned, at the tile where it happened.
+    pub fn observe(&mut self, state: &GameState, now: Instant) {
+        self.floating.retain(|f| now.saturating_duration_since(f.start) < FLOAT_DURATION);
+        if state.tick < self.tick || state.events.next_sequence() < self.next_event {
+            // A new game has started, enemy IDs and events are no longer
+            // comparable.
+            self.enemies.clear();
+            self.next_event = 0;
+        }
+        let ticked = state.tick != self.tick;
+        self.tick = state.tick;
+
+        for (_, event) in state.events.iter().filter(|(sequence, _)| *sequence >= self.next_event) {
+            let (kind, position, amount) = match *event {
+                GameEvent::EnemyDamaged { position, amount } => {
+                    (FloatingKind::Damage, position, amount)
+                }
+                GameEvent::EnemyDefeated { position, bounty, .. } => {
+                    (FloatingKind::Gold, position, bounty)
+                }
+                GameEvent::TowerSold { position, refund, .. } => {
+                    (FloatingKind::Gold, position, refund)
+                }
+                _ => continue,
+            };
+            self.floating.push(FloatingNumber { kind, amount, position, start: now });
+        }
+        self.next_event = state.events.next_sequence();
+
+        self.enemies = state
+            .enemies
+            .iter()
+            .map(|enemy| {
+                let previous_distance = match self.enemies.get(&enemy.id) {
+                    Some(seen) if ticked => seen.distance,
+                    Some(seen) => seen.previous_distance,
+                    None => enemy.distance,
+                };
+                (enemy.id, SeenEnemy { distance: enemy.distance, previous_distance })
+            })
+            .collect();
+    }
+
+    /// Distance along the path at which to draw an enemy, blending its
+    /// position before and after the most recent tick by `tick_progress`.
+    ///
+    /// Returns `None` if the enemy was not seen by the last call to
+    /// [Self::observe].
+    pub fn interpolated_distance(&self, id: EnemyId, tick_progress: f64) -> Option<f64> {
+        let seen = self.enemies.get(&id)?;
+        Some((seen.previous_distance as f64).lerp(seen.distance as f64, tick_progress))
+    }
+
+    /// Floating numbers currently on screen, oldest first.
+    pub fn floating(&self) -> &[FloatingNumber] {
+        &self.floating
+    }
+}
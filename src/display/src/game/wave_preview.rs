@@ -0,0 +1,131 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::actions::GameAction;
+use data::core::colors;
+use data::core::enemy::EnemyTrait;
+use data::core::game_state::GameState;
+use data::core::wave::WaveGroup;
+use data::core::widget_id::{WidgetId, WidgetName};
+use ratatui::prelude::*;
+use rules::core::waves;
+
+use crate::core::button::{Button, ButtonSize};
+use crate::core::layout::Edge;
+use crate::core::render_context::RenderContext;
+
+const ID: WidgetId = WidgetId::new(WidgetName::WavePreview);
+
+/// Maximum number of upcoming waves to list.
+const PREVIEW_WAVES: usize = 2;
+
+/// Lists the enemies in the next few waves, with a button to start the next
+/// wave early for bonus gold. The hotkey for sending early belongs to the
+/// [Hud](crate::game::hud::Hud), which is always visible.
+pub struct WavePreview<'a> {
+    pub state: &'a GameState,
+}
+
+impl<'a> StatefulWidget for WavePreview<'a> {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let state = self.state;
+        let upcoming = state.upcoming_waves();
+        if upcoming.is_empty() {
+            Line::from(label("No upcoming waves")).render(area, buf);
+            return;
+        }
+
+        let (list, button) = match waves::early_bonus(state) {
+            Some(bonus) => {
+                let (button, list) = context.anchor(area, Edge::Bottom, 1);
+                (list, Some((button, bonus)))
+            }
+            None => (area, None),
+        };
+
+        let mut lines = vec![];
+        for (i, wave) in upcoming.iter().take(PREVIEW_WAVES).enumerate() {
+            if i > 0 {
+                lines.push(Line::default());
+            }
+            let number = state.wave + i as u32 + 1;
+            lines.push(heading(state, number, i == 0));
+            lines.extend(wave.groups.iter().map(group_line));
+        }
+        Text::from(lines).render(list, buf);
+
+        if let Some((area, bonus)) = button {
+            Button::new()
+                .label("Send early")
+                .action(GameAction::SendWaveEarly)
+                .id(ID.child(WidgetName::SendEarly))
+                .secondary(format!("+{bonus}g"))
+                .size(ButtonSize::Small)
+                .build()
+                .render(area, buf, context);
+        }
+    }
+}
+
+/// Title line for a wave, including a countdown if it is the next to start.
+fn heading(state: &GameState, number: u32, next: bool) -> Line<'static> {
+    let mut spans = vec![format!("Wave {number}").fg(colors::white()).bold()];
+    if let Some(remaining) = state.time_until_next_wave().filter(|_| next) {
+        let seconds = remaining.as_secs_f64().ceil();
+        spans.push(label(" in "));
+        spans.push(format!("{seconds}s").fg(colors::white()));
+    }
+    Line::from(spans)
+}
+
+/// Describes one group as its glyph, name, count, spawn point and traits,
+/// e.g. "B Brute  ×2  @1 armored".
+fn group_line(group: &WaveGroup) -> Line<'static> {
+    let definition = group.enemy.definition();
+    let mut spans = vec![
+        " ".into(),
+        definition.glyph.fg(colors::peach()),
+        format!(" {:<7}", definition.name).fg(colors::white()),
+        format!("×{:<3}", group.count).fg(colors::white()),
+        label("@"),
+        (group.spawn_point + 1).to_string().fg(colors::light_gray()),
+    ];
+    for &enemy_trait in definition.traits {
+        spans.push(" ".into());
+        spans.push(trait_name(enemy_trait).fg(trait_color(enemy_trait)));
+    }
+    Line::from(spans)
+}
+
+fn trait_name(enemy_trait: EnemyTrait) -> &'static str {
+    match enemy_trait {
+        EnemyTrait::Flying => "flying",
+        EnemyTrait::Armored => "armored",
+        EnemyTrait::Boss => "boss",
+    }
+}
+
+fn trait_color(enemy_trait: EnemyTrait) -> Color {
+    match enemy_trait {
+        EnemyTrait::Flying => colors::light_blue(),
+        EnemyTrait::Armored => colors::blue_gray(),
+        EnemyTrait::Boss => colors::red(),
+    }
+}
+
+fn label(text: &'static str) -> Span<'static> {
+    text.fg(colors::light_gray())
+}
@@ -13,3 +13,6 @@
 // limitations under the License.
 
 pub mod core;
+pub mod game;
+pub mod menus;
+pub mod panels;
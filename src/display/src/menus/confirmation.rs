@@ -0,0 +1,85 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::{GameAction, InterfaceAction};
+use data::core::colors;
+use data::core::interface_state::Confirmation;
+use data::core::widget_id::{WidgetId, WidgetName};
+use ratatui::layout::Size;
+use ratatui::prelude::*;
+use ratatui::widgets::{Paragraph, Wrap};
+
+use crate::core::button::Button;
+use crate::core::render_context::RenderContext;
+use crate::core::window;
+
+const ID: WidgetId = WidgetId::new(WidgetName::ConfirmationDialog);
+
+/// Dialog asking the user to confirm a destructive choice.
+pub struct ConfirmationDialog {
+    pub confirmation: Confirmation,
+}
+
+impl StatefulWidget for ConfirmationDialog {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let (title, message, action) = match self.confirmation {
+            Confirmation::QuitToMenu => (
+                "Quit to Menu",
+                "Return to the main menu? Your progress in this game will be lost.",
+                InterfaceAction::QuitToMenu,
+            ),
+            Confirmation::QuitGame => (
+                "Quit Game",
+                "Exit termdef? Your progress in this game will be lost.",
+                InterfaceAction::Exit,
+            ),
+            Confirmation::SellTower(position) => (
+                "Sell Tower",
+                "Sell this tower? Its upgrades will be lost.",
+                GameAction::SellTower(position).into(),
+            ),
+        };
+
+        context.key_pressed(KeyCode::Char('y'), action);
+        context.key_pressed(KeyCode::Char('n'), InterfaceAction::CloseModal);
+        context.key_pressed(KeyCode::Esc, InterfaceAction::CloseModal);
+
+        let window = context.centered_rect(Size { width: 44, height: 8 }, area);
+        let inner = window::render(title, window, buf);
+        let [text, buttons] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(3)]).areas(inner);
+        Paragraph::new(message.fg(colors::white()))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .render(text, buf);
+
+        let [yes, no] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(buttons);
+        Button::new()
+            .label("Yes")
+            .action(action)
+            .id(ID.child(WidgetName::Yes))
+            .build()
+            .render(yes, buf, context);
+        Button::new()
+            .label("No")
+            .action(InterfaceAction::CloseModal)
+            .id(ID.child(WidgetName::No))
+            .build()
+            .render(no, buf, context);
+    }
+}
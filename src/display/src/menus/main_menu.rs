@@ -0,0 +1,72 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::InterfaceAction;
+use data::core::colors;
+use data::core::interface_state::Modal;
+use data::core::widget_id::{WidgetId, WidgetName};
+use ratatui::layout::Size;
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+use crate::core::button::Button;
+use crate::core::render_context::RenderContext;
+
+const ID: WidgetId = WidgetId::new(WidgetName::MainMenu);
+
+/// Screen displayed when the program starts and after quitting a game.
+pub struct MainMenu;
+
+impl StatefulWidget for MainMenu {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        context.key_pressed(KeyCode::Char('q'), InterfaceAction::Exit);
+
+        let menu = context.centered_rect(Size { width: 30, height: 13 }, area);
+        let [title, new_game, settings, quit] = Layout::vertical([
+            Constraint::Length(4),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+        ])
+        .areas(menu);
+
+        Paragraph::new(vec![
+            Line::from("termdef".bold().fg(colors::yellow())),
+            Line::from("Terminal Tower Defense".fg(colors::white())),
+        ])
+        .alignment(Alignment::Center)
+        .render(title, buf);
+        Button::new()
+            .label("New Game")
+            .action(InterfaceAction::NewGame)
+            .id(ID.child(WidgetName::NewGame))
+            .build()
+            .render(new_game, buf, context);
+        Button::new()
+            .label("Settings")
+            .action(InterfaceAction::ShowModal(Modal::Settings))
+            .id(ID.child(WidgetName::Settings))
+            .build()
+            .render(settings, buf, context);
+        Button::new()
+            .label("Quit")
+            .action(InterfaceAction::Exit)
+            .id(ID.child(WidgetName::Quit))
+            .build()
+            .render(quit, buf, context);
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::InterfaceAction;
+use data::core::interface_state::{Confirmation, Modal};
+use data::core::settings::KeyBindings;
+use data::core::widget_id::{WidgetId, WidgetName};
+use ratatui::layout::Size;
+use ratatui::prelude::*;
+
+use crate::core::button::Button;
+use crate::core::render_context::RenderContext;
+use crate::core::window;
+
+const ID: WidgetId = WidgetId::new(WidgetName::PauseMenu);
+
+/// Menu displayed while the game is paused.
+pub struct PauseMenu {
+    pub key_bindings: KeyBindings,
+}
+
+impl StatefulWidget for PauseMenu {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        context.key_pressed(KeyCode::Esc, InterfaceAction::CloseModal);
+        context.key_pressed(self.key_bindings.pause, InterfaceAction::CloseModal);
+        context.key_pressed(
+            self.key_bindings.quit,
+            InterfaceAction::ShowModal(Modal::Confirm(Confirmation::QuitGame)),
+        );
+
+        let window = context.centered_rect(Size { width: 30, height: 14 }, area);
+        let inner = window::render("Paused", window, buf);
+        let [resume, restart, settings, quit] =
+            Layout::vertical([Constraint::Length(3); 4]).areas(inner);
+
+        Button::new()
+            .label("Resume")
+            .action(InterfaceAction::CloseModal)
+            .id(ID.child(WidgetName::Resume))
+            .build()
+            .render(resume, buf, context);
+        Button::new()
+            .label("Restart Level")
+            .action(InterfaceAction::NewGame)
+            .id(ID.child(WidgetName::Restart))
+            .build()
+            .render(restart, buf, context);
+        Button::new()
+            .label("Settings")
+            .action(InterfaceAction::ShowModal(Modal::Settings))
+            .id(ID.child(WidgetName::Settings))
+            .build()
+            .render(settings, buf, context);
+        Button::new()
+            .label("Quit to Menu")
+            .action(InterfaceAction::ShowModal(Modal::Confirm(Confirmation::QuitToMenu)))
+            .id(ID.child(WidgetName::Quit))
+            .build()
+            .render(quit, buf, context);
+    }
+}
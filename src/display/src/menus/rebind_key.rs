@@ -0,0 +1,72 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::InterfaceAction;
+use data::core::colors;
+use data::core::settings::KeyBinding;
+use data::core::tower::TowerKind;
+use ratatui::layout::Size;
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+use crate::core::render_context::RenderContext;
+use crate::core::window;
+use crate::game::hud::SEND_EARLY_HOTKEY;
+use crate::game::tower_panel::{SELL_HOTKEY, UPGRADE_HOTKEYS};
+use crate::menus::settings_menu;
+
+/// Prompt which assigns the next key pressed to a [KeyBinding].
+///
+/// Keys with a fixed meaning during a game are ignored. Choosing a key which
+/// is assigned to another binding swaps the two.
+pub struct RebindKeyPrompt {
+    pub binding: KeyBinding,
+}
+
+impl StatefulWidget for RebindKeyPrompt {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let code = context.key_presses().find(|c| !is_reserved(*c));
+        if let Some(code) = code {
+            context.push_action(InterfaceAction::RebindKey(self.binding, code));
+        }
+        context.key_pressed(KeyCode::Esc, InterfaceAction::CloseModal);
+
+        let window = context.centered_rect(Size { width: 44, height: 7 }, area);
+        let inner = window::render("Rebind Key", window, buf);
+        Paragraph::new(vec![
+            Line::from(
+                format!("Press a new key for {}", settings_menu::binding_name(self.binding))
+                    .fg(colors::white()),
+            ),
+            Line::from(""),
+            Line::from("Game hotkeys cannot be rebound".fg(colors::light_gray())),
+            Line::from("Press Esc to cancel".fg(colors::light_gray())),
+        ])
+        .alignment(Alignment::Center)
+        .render(inner, buf);
+    }
+}
+
+/// True if the key has a fixed meaning during a game.
+fn is_reserved(code: KeyCode) -> bool {
+    code == KeyCode::Esc
+        || code == KeyCode::F(12)
+        || code == SEND_EARLY_HOTKEY
+        || code == SELL_HOTKEY
+        || UPGRADE_HOTKEYS.contains(&code)
+        || enum_iterator::all::<TowerKind>().any(|t| t.definition().hotkey == code)
+}
@@ -0,0 +1,305 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::InterfaceAction;
+use data::core::colors;
+use data::core::game_state::GameSpeed;
+use data::core::interface_state::Modal;
+use data::core::settings::{AutosaveFrequency, ColorDepth, KeyBinding, Settings, Theme};
+use data::core::widget_id::{WidgetId, WidgetName};
+use enum_iterator::next_cycle;
+use ratatui::layout::Size;
+use ratatui::prelude::*;
+
+use crate::core::button::Button;
+use crate::core::checkbox::Checkbox;
+use crate::core::layout::Insets;
+use crate::core::render_context::RenderContext;
+use crate::core::slider::Slider;
+use crate::core::{keys, window};
+
+const ID: WidgetId = WidgetId::new(WidgetName::SettingsMenu);
+
+/// Window for configuring game options.
+///
+/// On/off options are displayed as checkboxes, the game speed as a slider,
+/// and every other option as a row which cycles to its next value when
+/// clicked. The Up and Down keys highlight a row, or the Back button, and
+/// Enter or Space activates it. Left and Right adjust a highlighted slider.
+pub struct SettingsMenu {
+    pub settings: Settings,
+}
+
+/// Control used to display and change one item in the menu.
+enum Control {
+    /// Row showing the current value, which cycles when clicked.
+    Value(String),
+    Checkbox(bool),
+    GameSpeed,
+    Button,
+}
+
+/// One keyboard-selectable item in the menu. The action is produced when the
+/// item is activated by clicking it or by pressing Enter or Space.
+struct Item {
+    id: WidgetId,
+    label: &'static str,
+    control: Control,
+    action: InterfaceAction,
+}
+
+impl StatefulWidget for SettingsMenu {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        context.key_pressed(KeyCode::Esc, InterfaceAction::CloseModal);
+
+        let window = context.centered_rect(Size { width: 44, height: 20 }, area);
+        let inner = window::render("Settings", window, buf);
+        let [rows, back] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(3)]).areas(inner);
+        let rows = Layout::vertical([Constraint::Length(1); 15]).split(rows);
+
+        let s = self.settings;
+        let update = |f: &dyn Fn(&mut Settings)| {
+            let mut result = s;
+            f(&mut result);
+            InterfaceAction::UpdateSettings(result)
+        };
+        let item =
+            |name, label, control, action| Item { id: ID.child(name), label, control, action };
+
+        let options = [
+            item(
+                WidgetName::GameSpeed,
+                "Game Speed",
+                Control::GameSpeed,
+                update(&|s| s.game_speed = next_cycle(&s.game_speed)),
+            ),
+            item(
+                WidgetName::Theme,
+                "Theme",
+                Control::Value(theme_name(s.theme).to_string()),
+                update(&|s| s.theme = next_cycle(&s.theme)),
+            ),
+            item(
+                WidgetName::ColorDepth,
+                "Color Depth",
+                Control::Value(color_depth_name(s.color_depth).to_string()),
+                update(&|s| s.color_depth = next_cycle(&s.color_depth)),
+            ),
+            item(
+                WidgetName::MouseCapture,
+                "Mouse Capture",
+                Control::Checkbox(s.mouse_capture),
+                update(&|s| s.mouse_capture = !s.mouse_capture),
+            ),
+            item(
+                WidgetName::RangeCircles,
+                "Range Circles",
+                Control::Checkbox(s.show_range_circles),
+                update(&|s| s.show_range_circles = !s.show_range_circles),
+            ),
+            item(
+                WidgetName::HealthBars,
+                "Health Bars",
+                Control::Checkbox(s.show_health_bars),
+                update(&|s| s.show_health_bars = !s.show_health_bars),
+            ),
+            item(
+                WidgetName::DamageNumbers,
+                "Damage Numbers",
+                Control::Checkbox(s.show_damage_numbers),
+                update(&|s| s.show_damage_numbers = !s.show_damage_numbers),
+            ),
+            item(
+                WidgetName::ScaleMap,
+                "Scale Map",
+                Control::Checkbox(s.scale_map),
+                update(&|s| s.scale_map = !s.scale_map),
+            ),
+            item(
+                WidgetName::Autosave,
+                "Autosave",
+                Control::Value(autosave_name(s.autosave).to_string()),
+                update(&|s| s.autosave = next_cycle(&s.autosave)),
+            ),
+        ];
+
+        let count = options.len();
+        let bindings = enum_iterator::all::<KeyBinding>().enumerate().map(|(i, binding)| Item {
+            id: ID.child(WidgetName::KeyBinding).index(i),
+            label: binding_name(binding),
+            control: Control::Value(keys::name(s.key_bindings.get(binding))),
+            action: InterfaceAction::ShowModal(Modal::RebindKey(binding)),
+        });
+        let mut items = options.into_iter().chain(bindings).collect::<Vec<_>>();
+        items.push(item(WidgetName::Back, "Back", Control::Button, InterfaceAction::CloseModal));
+        let highlighted = highlight(context, &items);
+
+        let mut areas = rows[..count].iter().chain(&rows[count + 2..]);
+        for (i, Item { id, label, control, action }) in items.into_iter().enumerate() {
+            let highlighted = highlighted == Some(i);
+            if let Control::Button = control {
+                Button::new()
+                    .label(label)
+                    .action(action)
+                    .id(id)
+                    .selected(highlighted)
+                    .build()
+                    .render(back, buf, context);
+                continue;
+            }
+            let Some(&row) = areas.next() else {
+                continue;
+            };
+            if highlighted {
+                buf.set_style(row, Style::new().bg(colors::dark_blue()));
+            }
+            match control {
+                Control::Value(value) => {
+                    SettingRow { id, label, value, action }.render(row, buf, context)
+                }
+                Control::Checkbox(checked) => Checkbox::new()
+                    .label(label)
+                    .checked(checked)
+                    .action(action)
+                    .id(id)
+                    .build()
+                    .render(
+                        context.inset(row, Insets { left: 1, ..Default::default() }),
+                        buf,
+                        context,
+                    ),
+                Control::GameSpeed => {
+                    let [name, slider] =
+                        Layout::horizontal([Constraint::Length(LABEL_WIDTH), Constraint::Fill(1)])
+                            .areas(row);
+                    Line::from(format!(" {label}").fg(colors::white())).render(name, buf);
+                    speed_slider(id, s, highlighted).render(slider, buf, context);
+                }
+                Control::Button => {}
+            }
+        }
+        Line::from(" Key Bindings".fg(colors::light_gray())).render(rows[count + 1], buf);
+    }
+}
+
+/// Width of the column of option names, including the leading space.
+const LABEL_WIDTH: u16 = 19;
+
+/// Slider selecting the speed at which new games start.
+fn speed_slider(id: WidgetId, settings: Settings, focused: bool) -> Slider {
+    let speed = |i| enum_iterator::all::<GameSpeed>().nth(i as usize).unwrap_or_default();
+    let index = enum_iterator::all::<GameSpeed>().position(|s| s == settings.game_speed);
+    Slider::new()
+        .id(id)
+        .value(index.unwrap_or_default() as u32)
+        .min(0)
+        .max(enum_iterator::cardinality::<GameSpeed>() as u32 - 1)
+        .on_change(move |i| {
+            InterfaceAction::UpdateSettings(Settings { game_speed: speed(i), ..settings })
+        })
+        .format(move |i| speed_name(speed(i)).to_string())
+        .focused(focused)
+        .build()
+}
+
+/// Moves the keyboard highlight in response to the arrow keys and activates
+/// the highlighted item on Enter or Space. Returns the index of the
+/// highlighted item, if any.
+fn highlight(context: &mut RenderContext, items: &[Item]) -> Option<usize> {
+    let last = items.len() - 1;
+    let mut highlighted = context.highlighted(ID);
+    for code in context.key_presses().collect::<Vec<_>>() {
+        match (code, highlighted) {
+            (KeyCode::Up, Some(i)) => highlighted = Some(i.saturating_sub(1)),
+            (KeyCode::Down, Some(i)) => highlighted = Some((i + 1).min(last)),
+            (KeyCode::Up | KeyCode::Down, None) => highlighted = Some(0),
+            (KeyCode::Enter | KeyCode::Char(' '), Some(i)) => context.push_action(items[i].action),
+            _ => {}
+        }
+    }
+    if let Some(index) = highlighted {
+        context.set_highlighted(ID, index);
+    }
+    highlighted
+}
+
+/// Single line displaying the current value of an option.
+struct SettingRow {
+    id: WidgetId,
+    label: &'static str,
+    value: String,
+    action: InterfaceAction,
+}
+
+impl StatefulWidget for SettingRow {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        context.register(self.id, area);
+        let hovered = context.hovered(self.id, area);
+        let pressed = context.mouse_down(self.id, area);
+        context.clicked(self.id, area, self.action);
+
+        let mut value = self.value.fg(colors::yellow());
+        value = if pressed { value.underlined() } else { value };
+        let name = format!(" {:<width$}", self.label, width = LABEL_WIDTH as usize - 1);
+        Line::from(vec![name.fg(colors::white()), value]).render(area, buf);
+        if hovered {
+            buf.set_style(area, Style::new().bg(colors::selected()));
+        }
+    }
+}
+
+pub fn speed_name(speed: GameSpeed) -> &'static str {
+    match speed {
+        GameSpeed::Normal => "1x",
+        GameSpeed::Fast => "2x",
+        GameSpeed::Fastest => "4x",
+    }
+}
+
+pub fn binding_name(binding: KeyBinding) -> &'static str {
+    match binding {
+        KeyBinding::Pause => "Pause",
+        KeyBinding::ChangeSpeed => "Change Speed",
+        KeyBinding::Quit => "Quit",
+    }
+}
+
+fn theme_name(theme: Theme) -> &'static str {
+    match theme {
+        Theme::Standard => "Standard",
+        Theme::Grayscale => "Grayscale",
+    }
+}
+
+fn color_depth_name(color_depth: ColorDepth) -> &'static str {
+    match color_depth {
+        ColorDepth::TrueColor => "True Color",
+        ColorDepth::Ansi256 => "256 Colors",
+        ColorDepth::Ansi16 => "16 Colors",
+    }
+}
+
+fn autosave_name(autosave: AutosaveFrequency) -> &'static str {
+    match autosave {
+        AutosaveFrequency::Never => "Never",
+        AutosaveFrequency::EveryWave => "Every Wave",
+        AutosaveFrequency::EveryFiveWaves => "Every 5 Waves",
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use data::core::colors;
+use data::core::game_event::GameEvent;
+use data::core::game_state::GameState;
+use ratatui::prelude::*;
+
+/// Maximum number of entries kept in the [EventHistory].
+pub const EVENT_HISTORY_LENGTH: usize = 50;
+
+/// Descriptions of recent notable game events, oldest first.
+///
+/// Events are recorded by [Self::observe] as they are produced by the
+/// simulation, whether or not the [EventLog] is currently visible.
+#[derive(Debug, Default)]
+pub struct EventHistory {
+    tick: u64,
+
+    /// Sequence number of the first game event not yet recorded.
+    next_event: u64,
+    entries: VecDeque<Line<'static>>,
+}
+
+impl EventHistory {
+    /// Records an entry for each game event which has not been seen yet.
+    /// Should be called once per frame during a game.
+    pub fn observe(&mut self, state: &GameState) {
+        if state.tick < self.tick || state.events.next_sequence() < self.next_event {
+            // A new game has started.
+            *self = Self::default();
+        }
+        self.tick = state.tick;
+
+        for (_, event) in state.events.iter().filter(|(sequence, _)| *sequence >= self.next_event) {
+            if let Some(entry) = describe(event) {
+                if self.entries.len() == EVENT_HISTORY_LENGTH {
+                    self.entries.pop_front();
+                }
+                self.entries.push_back(entry);
+            }
+        }
+        self.next_event = state.events.next_sequence();
+    }
+
+    pub fn entries(&self) -> &VecDeque<Line<'static>> {
+        &self.entries
+    }
+}
+
+/// Lists the most recent entries in the [EventHistory], newest at the bottom.
+pub struct EventLog<'a> {
+    pub history: &'a EventHistory,
+}
+
+impl<'a> Widget for EventLog<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let entries = self.history.entries();
+        if entries.is_empty() {
+            Line::from("Nothing has happened yet".fg(colors::light_gray())).render(area, buf);
+            return;
+        }
+        let shown = entries.len().min(area.height as usize);
+        Text::from(entries.range(entries.len() - shown..).cloned().collect::<Vec<_>>())
+            .render(area, buf);
+    }
+}
+
+/// Entry describing `event`, or `None` if it is too minor to list.
+fn describe(event: &GameEvent) -> Option<Line<'static>> {
+    let line = match *event {
+        GameEvent::WaveStarted { wave } => {
+            Line::from(format!("Wave {wave} started").fg(colors::white()).bold())
+        }
+        GameEvent::WaveSentEarly { bonus } => {
+            Line::from(vec!["Sent early ".fg(colors::white()), gold(bonus)])
+        }
+        GameEvent::TowerBuilt { kind, .. } => {
+            Line::from(format!("{} built", kind.definition().name).fg(colors::white()))
+        }
+        GameEvent::TowerSold { kind, refund, .. } => Line::from(vec![
+            format!("{} sold ", kind.definition().name).fg(colors::white()),
+            gold(refund),
+        ]),
+        GameEvent::EnemyDamaged { .. } => return None,
+        GameEvent::EnemyDefeated { kind, bounty, .. } => Line::from(vec![
+            format!("{} defeated ", kind.definition().name).fg(colors::white()),
+            gold(bounty),
+        ]),
+        GameEvent::EnemyEscaped { kind } => Line::from(vec![
+            format!("{} escaped ", kind.definition().name).fg(colors::white()),
+            "-1♥".fg(colors::red()),
+        ]),
+    };
+    Some(line)
+}
+
+fn gold(amount: u32) -> Span<'static> {
+    format!("+{amount}g").fg(colors::yellow())
+}
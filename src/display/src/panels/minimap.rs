@@ -0,0 +1,85 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::colors;
+use data::core::game_state::GameState;
+use data::core::map::{Terrain, TilePosition, MAP_HEIGHT, MAP_WIDTH};
+use ratatui::prelude::*;
+
+/// Overview of the whole map, drawn with two pixels per cell so that it fits
+/// in a side panel. Each pixel covers a block of tiles and shows the most
+/// important thing in it: an enemy, then a tower, then the path, then the
+/// terrain.
+pub struct Minimap<'a> {
+    pub state: &'a GameState,
+}
+
+impl<'a> Widget for Minimap<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Largest scale which fits the map into the area, keeping its
+        // proportions
+        let scale = (area.width as f64 / MAP_WIDTH as f64)
+            .min(area.height as f64 * 2.0 / MAP_HEIGHT as f64)
+            .min(1.0);
+        let width = (MAP_WIDTH as f64 * scale).floor() as u16;
+        let rows = (MAP_HEIGHT as f64 * scale / 2.0).ceil() as u16;
+        if width == 0 || rows == 0 {
+            return;
+        }
+
+        let x = area.x + (area.width - width) / 2;
+        let tiles = |pixel: u16, pixels: u16, size: u16| {
+            let start = pixel as u32 * size as u32 / pixels as u32;
+            let end = (pixel as u32 + 1) * size as u32 / pixels as u32;
+            start as u16..(end as u16).max(start as u16 + 1).min(size)
+        };
+        for row in 0..rows.min(area.height) {
+            for column in 0..width {
+                let xs = tiles(column, width, MAP_WIDTH);
+                let color = |pixel: u16| {
+                    pixel_color(self.state, xs.clone(), tiles(pixel, rows * 2, MAP_HEIGHT))
+                };
+                buf.get_mut(x + column, area.y + row)
+                    .set_symbol("▀")
+                    .set_fg(color(row * 2))
+                    .set_bg(color(row * 2 + 1));
+            }
+        }
+    }
+}
+
+/// Color of the pixel covering the tiles in the given ranges.
+fn pixel_color(state: &GameState, xs: std::ops::Range<u16>, ys: std::ops::Range<u16>) -> Color {
+    let covers = |p: TilePosition| xs.contains(&p.x) && ys.contains(&p.y);
+    if state.enemies.iter().any(|e| e.position(&state.map).is_some_and(covers)) {
+        return colors::red();
+    }
+    if state.towers.iter().any(|t| covers(t.position)) {
+        return colors::white();
+    }
+    let tiles = ys.flat_map(|y| xs.clone().map(move |x| TilePosition::new(x, y)));
+    let mut terrain = None;
+    for tile in tiles {
+        if state.map.is_path(tile) {
+            return colors::brown();
+        }
+        terrain.get_or_insert(state.map.terrain(tile));
+    }
+    match terrain.unwrap_or(Terrain::Grass) {
+        Terrain::Grass => colors::black(),
+        Terrain::Forest => colors::dark_green(),
+        Terrain::Water => colors::dark_blue(),
+        Terrain::Rock => colors::blue_gray(),
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::game_state::GameState;
+use data::core::interface_state::InterfaceState;
+use data::core::map::TilePosition;
+use ratatui::prelude::*;
+use rules::core::towers;
+
+use crate::core::layout::{Insets, SIDE_PANEL_WIDTH};
+use crate::core::render_context::RenderContext;
+use crate::core::window;
+use crate::game::wave_preview::WavePreview;
+use crate::panels::event_log::{EventHistory, EventLog};
+use crate::panels::minimap::Minimap;
+use crate::panels::tower_details::TowerDetails;
+
+/// Supplementary information displayed beside the game area when the
+/// terminal is large enough.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SidePanel {
+    WavePreview,
+    EventLog,
+    TowerDetails,
+    Minimap,
+}
+
+impl SidePanel {
+    /// Panels displayed to the left of the game area, from top to bottom.
+    pub const LEFT: [SidePanel; 2] = [SidePanel::EventLog, SidePanel::Minimap];
+    /// Panels displayed to the right of the game area, from top to bottom.
+    pub const RIGHT: [SidePanel; 2] = [SidePanel::TowerDetails, SidePanel::WavePreview];
+
+    fn title(self) -> &'static str {
+        match self {
+            SidePanel::WavePreview => "Next Wave",
+            SidePanel::EventLog => "Events",
+            SidePanel::TowerDetails => "Tower",
+            SidePanel::Minimap => "Map",
+        }
+    }
+}
+
+/// Information displayed by [SidePanel]s.
+#[derive(Copy, Clone)]
+pub struct PanelData<'a> {
+    pub state: &'a GameState,
+    pub interface: &'a InterfaceState,
+    pub history: &'a EventHistory,
+
+    /// Map tile which the mouse is currently over, if any.
+    pub hovered_tile: Option<TilePosition>,
+}
+
+impl SidePanel {
+    fn render(self, data: PanelData, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let inner = window::render(self.title(), area, buf);
+        let content = context.inset(inner, Insets::symmetric(1, 0));
+        let state = data.state;
+        match self {
+            SidePanel::WavePreview => WavePreview { state }.render(content, buf, context),
+            SidePanel::EventLog => EventLog { history: data.history }.render(content, buf),
+            SidePanel::TowerDetails => {
+                let tower = data
+                    .interface
+                    .selected_tower
+                    .and_then(|p| towers::at(state, p))
+                    .or_else(|| data.hovered_tile.and_then(|p| towers::at(state, p)));
+                TowerDetails { tower }.render(content, buf, context)
+            }
+            SidePanel::Minimap => Minimap { state }.render(content, buf),
+        }
+    }
+}
+
+/// Renders a column of [SidePanel]s, dividing `area` evenly between them.
+pub fn render_column(
+    panels: &[SidePanel],
+    data: PanelData,
+    area: Rect,
+    buf: &mut Buffer,
+    context: &mut RenderContext,
+) {
+    debug_assert_eq!(area.width, SIDE_PANEL_WIDTH);
+    let rows = Layout::vertical(vec![Constraint::Fill(1); panels.len()]).split(area);
+    for (panel, row) in panels.iter().zip(rows.iter()) {
+        panel.render(data, *row, buf, context);
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::colors;
+use data::core::tower::Tower;
+use ratatui::prelude::*;
+use ratatui::widgets::{Paragraph, Wrap};
+use rules::core::towers;
+
+use crate::core::layout::Edge;
+use crate::core::render_context::RenderContext;
+use crate::game::tower_panel;
+use crate::game::tower_panel::TowerSummary;
+
+/// Details of the selected tower, or else the tower under the mouse,
+/// including the upgrades it has been given and the gold invested in it. Unlike
+/// the [TowerPanel](crate::game::tower_panel::TowerPanel), this offers no
+/// actions.
+pub struct TowerDetails<'a> {
+    pub tower: Option<&'a Tower>,
+}
+
+impl<'a> StatefulWidget for TowerDetails<'a> {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let Some(tower) = self.tower else {
+            Paragraph::new(label("Select a tower to see its details"))
+                .wrap(Wrap { trim: true })
+                .render(area, buf);
+            return;
+        };
+
+        let (summary, rest) = context.anchor(area, Edge::Top, TowerSummary::HEIGHT + 1);
+        TowerSummary { tower }.render(summary, buf);
+        let mut lines = vec![
+            Line::from(vec![
+                label(" Target   "),
+                tower_panel::targeting_name(tower.targeting).fg(colors::white()),
+            ]),
+            Line::from(vec![
+                label(" Invested "),
+                format!("{}g", tower.invested).fg(colors::white()),
+            ]),
+            Line::from(vec![
+                label(" Sells for "),
+                format!("{}g", towers::sell_value(tower)).fg(colors::yellow()),
+            ]),
+            Line::default(),
+            Line::from(label(" Upgrades")),
+        ];
+        let nodes = tower.definition().upgrades.nodes;
+        if tower.upgrades.is_empty() {
+            lines.push(Line::from(label("  None")));
+        }
+        lines.extend(
+            tower
+                .upgrades
+                .iter()
+                .map(|&i| Line::from(format!("  {}", nodes[i].name).fg(colors::white()))),
+        );
+        Text::from(lines).render(rest, buf);
+    }
+}
+
+fn label(text: &'static str) -> Span<'static> {
+    text.fg(colors::light_gray())
+}
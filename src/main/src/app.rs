@@ -12,91 +12,567 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::future::Future;
 use std::time::Duration;
 
 use color_eyre::Result;
-use crossterm::event;
-use data::core::actions::InterfaceAction;
-use data::core::game_state::GameState;
-use display::core::layout;
+use crossterm::event::{Event, EventStream, KeyCode};
+use data::core::actions::{GameAction, InterfaceAction};
+use data::core::colors::Palette;
+use data::core::game_state::{GameState, TICK_DURATION};
+use data::core::interface_state::{Confirmation, InterfaceState, Modal, Screen, Tool};
+use data::core::map::TilePosition;
+use data::core::settings::Settings;
+use display::core::layout::{GameLayout, ScreenLayout, ScreenOptions};
 use display::core::render_context::RenderContext;
-use ratatui::layout::Size;
+use display::core::scale::Scale;
+use display::game::battlefield::Battlefield;
+use display::game::hud::Hud;
+use display::game::shop::Shop;
+use display::game::status_line::StatusLine;
+use display::game::tower_panel::TowerPanel;
+use display::game::visual_effects::VisualEffects;
+use display::menus::confirmation::ConfirmationDialog;
+use display::menus::main_menu::MainMenu;
+use display::menus::pause_menu::PauseMenu;
+use display::menus::rebind_key::RebindKeyPrompt;
+use display::menus::settings_menu::SettingsMenu;
+use display::panels::event_log::EventHistory;
+use display::panels::side_panel;
+use display::panels::side_panel::{PanelData, SidePanel};
+use futures::StreamExt;
 use ratatui::prelude::*;
 use ratatui::symbols::border;
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
-use rules::core::{handle_action, new_game};
-use tracing::info;
+use rules::core::{handle_action, new_game, tick, towers};
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tokio::time;
+use tokio::time::Instant;
+use tracing::{error, info};
 
-use crate::tui::Tui;
+use crate::settings_file::SettingsWriter;
+use crate::tui;
 
-pub fn run(tui: &mut Tui) -> Result<()> {
-    let mut data = new_game::create();
-    let mut context = RenderContext::default();
-    while !context.should_exit() {
-        context.set_last_event(if event::poll(Duration::from_millis(16))? {
-            Some(event::read()?)
-        } else {
-            None
-        });
-        tui.draw(|frame| {
-            frame.render_stateful_widget(App { data: &data }, frame.size(), &mut context);
+/// Minimum amount of time between frames which are rendered in response to
+/// simulation ticks or background messages.
+const FRAME_DURATION: Duration = Duration::from_millis(16);
 
-            let Some(action) = context.finish_render() else {
-                return;
-            };
+/// Maximum number of simulation ticks run at once to catch up after the main
+/// loop falls behind, e.g. because the process was suspended. Ticks beyond
+/// this are skipped rather than freezing the game while they run.
+pub const MAX_CATCH_UP_TICKS: u32 = 20;
+
+/// Source of input events for the main loop.
+pub trait EventSource {
+    /// Waits for the next input event.
+    ///
+    /// Returns `None` if this source will not produce any further events, in
+    /// which case the main loop stops. Must be cancellation safe.
+    fn next(&mut self) -> impl Future<Output = Result<Option<Event>>>;
+}
+
+/// Reads events from the real terminal.
+pub struct TerminalEvents {
+    stream: EventStream,
+}
+
+impl TerminalEvents {
+    pub fn new() -> Self {
+        Self { stream: EventStream::new() }
+    }
+}
+
+impl Default for TerminalEvents {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventSource for TerminalEvents {
+    async fn next(&mut self) -> Result<Option<Event>> {
+        Ok(self.stream.next().await.transpose()?)
+    }
+}
+
+/// Applies changes to [Settings] outside of the main loop, e.g. to the
+/// terminal and to disk.
+pub trait SettingsSink {
+    /// Called whenever the settings change from `previous` to `new`.
+    fn settings_changed(&mut self, previous: Settings, new: Settings);
+}
+
+/// Applies settings to the real terminal and saves them to the settings
+/// file.
+pub struct TerminalSettings {
+    writer: SettingsWriter,
+}
+
+impl TerminalSettings {
+    pub fn new() -> Self {
+        Self { writer: SettingsWriter::spawn() }
+    }
+}
 
-            match action {
-                InterfaceAction::GameAction(game_action) => {
-                    info!(?game_action, "Handling GameAction");
-                    handle_action::handle_game_action(&mut data, game_action);
+impl Default for TerminalSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SettingsSink for TerminalSettings {
+    fn settings_changed(&mut self, previous: Settings, new: Settings) {
+        if new.mouse_capture != previous.mouse_capture {
+            if let Err(e) = tui::set_mouse_capture(new.mouse_capture) {
+                error!(?e, "Unable to change mouse capture");
+            }
+        }
+        self.writer.save(new);
+    }
+}
+
+/// All state owned by the main loop.
+pub struct AppState {
+    pub data: GameState,
+    pub interface: InterfaceState,
+    pub settings: Settings,
+    pub context: RenderContext,
+
+    /// Decorations drawn on top of the battlefield, which persist between
+    /// frames.
+    pub effects: VisualEffects,
+
+    /// Recent game events listed by the event log.
+    pub history: EventHistory,
+    settings_sink: Box<dyn SettingsSink>,
+    next_tick: Instant,
+
+    /// True if the terminal was too small to display the game when last
+    /// rendered, which freezes the simulation.
+    too_small: bool,
+    sender: UnboundedSender<InterfaceAction>,
+    receiver: UnboundedReceiver<InterfaceAction>,
+}
+
+impl AppState {
+    pub fn new(settings: Settings, settings_sink: impl SettingsSink + 'static) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let mut context = RenderContext::default();
+        context.set_palette(Palette::from_settings(&settings));
+        Self {
+            data: new_game::create(settings.game_speed),
+            interface: InterfaceState::default(),
+            settings,
+            context,
+            effects: VisualEffects::default(),
+            history: EventHistory::default(),
+            settings_sink: Box::new(settings_sink),
+            next_tick: Instant::now(),
+            too_small: false,
+            sender,
+            receiver,
+        }
+    }
+
+    /// Returns a channel which background tasks can use to send actions to
+    /// the main loop.
+    pub fn action_sender(&self) -> UnboundedSender<InterfaceAction> {
+        self.sender.clone()
+    }
+
+    /// True if the game simulation is currently advancing.
+    fn is_running(&self) -> bool {
+        self.interface.screen == Screen::Game && !self.interface.is_paused() && !self.too_small
+    }
+
+    /// Fraction of the way from the most recent simulation tick to the next
+    /// one at time `now`. Always 1.0 while the simulation is not running, so
+    /// that the latest state is displayed.
+    fn tick_progress(&self, now: Instant) -> f64 {
+        if !self.is_running() {
+            return 1.0;
+        }
+        let period = TICK_DURATION / self.data.speed.multiplier();
+        let remaining = self.next_tick.saturating_duration_since(now);
+        1.0 - remaining.as_secs_f64() / period.as_secs_f64()
+    }
+
+    fn handle_action(&mut self, action: InterfaceAction) {
+        match action {
+            InterfaceAction::GameAction(game_action) => {
+                info!(?game_action, "Handling GameAction");
+                handle_action::handle_game_action(&mut self.data, game_action);
+                if let GameAction::SellTower(position) = game_action {
+                    if self.interface.selected_tower == Some(position) {
+                        self.interface.selected_tower = None;
+                    }
+                    let confirm = Modal::Confirm(Confirmation::SellTower(position));
+                    if self.interface.current_modal() == Some(confirm) {
+                        self.interface.modals.pop();
+                    }
                 }
-                InterfaceAction::SetHover(id) => {
-                    context.set_current_hover(id);
+            }
+            InterfaceAction::SetHover(id) => {
+                self.context.set_current_hover(id);
+            }
+            InterfaceAction::SetMouseDown(id) => {
+                self.context.set_current_mouse_down(id);
+            }
+            InterfaceAction::SetScrollOffset(id, offset) => {
+                self.context.set_scroll_offset(id, offset);
+            }
+            InterfaceAction::SelectTower(position) => {
+                self.interface.selected_tower = position;
+            }
+            InterfaceAction::SetTool(tool) => {
+                self.interface.tool = tool;
+                self.interface.selected_tower = None;
+            }
+            InterfaceAction::ShowModal(modal) => {
+                if self.interface.current_modal() != Some(modal) {
+                    self.interface.modals.push(modal);
                 }
-                InterfaceAction::SetMouseDown(id) => {
-                    context.set_current_mouse_down(id);
+            }
+            InterfaceAction::CloseModal => {
+                self.interface.modals.pop();
+            }
+            InterfaceAction::NewGame => {
+                info!("Starting new game");
+                self.data = new_game::create(self.settings.game_speed);
+                self.context.start_new_game();
+                self.effects = VisualEffects::default();
+                self.history = EventHistory::default();
+                self.interface.screen = Screen::Game;
+                self.interface.modals.clear();
+                self.interface.tool = Tool::Select;
+                self.interface.selected_tower = None;
+            }
+            InterfaceAction::QuitToMenu => {
+                info!("Quitting to main menu");
+                self.interface.screen = Screen::MainMenu;
+                self.interface.modals.clear();
+                self.interface.tool = Tool::Select;
+                self.interface.selected_tower = None;
+            }
+            InterfaceAction::UpdateSettings(new) => {
+                self.update_settings(new);
+            }
+            InterfaceAction::RebindKey(binding, code) => {
+                let mut new = self.settings;
+                new.key_bindings.rebind(binding, code);
+                self.update_settings(new);
+                self.interface.modals.pop();
+            }
+            InterfaceAction::ToggleDebugLayout => {
+                self.interface.debug_layout = !self.interface.debug_layout;
+            }
+            InterfaceAction::Exit => {
+                self.context.set_should_exit();
+            }
+        };
+    }
+
+    fn update_settings(&mut self, new: Settings) {
+        info!(?new, "Updating settings");
+        let previous = self.settings;
+        self.settings = new;
+        self.context.set_palette(Palette::from_settings(&new));
+        self.settings_sink.settings_changed(previous, new);
+    }
+}
+
+/// Runs the main loop until the user exits or `events` is finished.
+///
+/// Frames are rendered at most once per `FRAME_DURATION`, and only when input
+/// has been received or something else, such as a simulation tick, has
+/// changed, or when a widget has requested a redraw at a specific time. Input
+/// is delivered to widgets in order, see [RenderContext]. Nothing is rendered
+/// while the game is idle.
+pub async fn run<B: Backend>(
+    terminal: &mut Terminal<B>,
+    events: &mut impl EventSource,
+    state: &mut AppState,
+) -> Result<()> {
+    let mut dirty = render(terminal, state)?;
+    let mut last_render = Instant::now();
+    while !state.context.should_exit() {
+        let running = state.is_running();
+        if !running {
+            // Don't try to catch up on ticks missed while the simulation was frozen
+            state.next_tick = Instant::now() + TICK_DURATION;
+        }
+
+        let redraw_at = state.context.redraw_at().map(Instant::from_std);
+
+        tokio::select! {
+            biased;
+
+            _ = time::sleep_until(state.next_tick), if running => {
+                let mut ticks = 0;
+                while Instant::now() >= state.next_tick {
+                    let period = TICK_DURATION / state.data.speed.multiplier();
+                    if ticks == MAX_CATCH_UP_TICKS {
+                        info!("Skipping simulation ticks to catch up");
+                        state.next_tick = Instant::now() + period;
+                        break;
+                    }
+                    tick::run(&mut state.data);
+                    state.next_tick += period;
+                    ticks += 1;
                 }
-            };
-        })?;
+                dirty = true;
+            }
+            Some(action) = state.receiver.recv() => {
+                state.handle_action(action);
+                dirty = true;
+            }
+            _ = time::sleep_until(redraw_at.unwrap_or(last_render)), if !dirty && redraw_at.is_some() => {
+                dirty = true;
+            }
+            _ = time::sleep_until(last_render + FRAME_DURATION), if dirty => {
+                dirty = render(terminal, state)?;
+                last_render = Instant::now();
+            }
+            event = events.next() => {
+                let Some(event) = event? else {
+                    break;
+                };
+                state.context.push_event(event);
+                dirty = true;
+            }
+        }
+    }
+
+    while dirty {
+        dirty = render(terminal, state)?;
     }
     Ok(())
 }
 
+/// Draws a single frame and handles all actions produced by it, in order.
+///
+/// Returns true if any action was handled and the frame is now out of date,
+/// or if there is queued input which has not been delivered yet.
+fn render<B: Backend>(terminal: &mut Terminal<B>, state: &mut AppState) -> Result<bool> {
+    let mut actions = vec![];
+    let now = Instant::now();
+    let was_too_small = state.too_small;
+    state.context.start_frame(now.into_std());
+    state.context.set_tick_progress(state.tick_progress(now));
+    if state.interface.screen == Screen::Game {
+        state.effects.observe(&state.data, now.into_std());
+        state.history.observe(&state.data);
+    }
+    terminal.draw(|frame| {
+        state.too_small = !ScreenLayout::fits(frame.size());
+        frame.render_stateful_widget(
+            App {
+                data: &state.data,
+                interface: &state.interface,
+                settings: &state.settings,
+                effects: &state.effects,
+                history: &state.history,
+            },
+            frame.size(),
+            &mut state.context,
+        );
+        actions = state.context.finish_render();
+    })?;
+    if was_too_small && !state.too_small {
+        // Don't catch up on ticks missed while the game could not be seen
+        state.next_tick = now + TICK_DURATION;
+    }
+    // These have been observed by the visual effects and event history above.
+    state.data.events.clear();
+
+    let changed = !actions.is_empty();
+    for action in actions {
+        state.handle_action(action);
+    }
+    Ok(changed || state.context.has_queued_events())
+}
+
 pub struct App<'a> {
     pub data: &'a GameState,
+    pub interface: &'a InterfaceState,
+    pub settings: &'a Settings,
+    pub effects: &'a VisualEffects,
+    pub history: &'a EventHistory,
 }
 
 impl<'a> StatefulWidget for App<'a> {
     type State = RenderContext;
 
-    fn render(self, area: Rect, buf: &mut Buffer, _context: &mut RenderContext) {
-        if area.width < 80 || area.height < 24 {
-            Paragraph::new(vec![
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        if cfg!(debug_assertions) {
+            context.key_pressed(KeyCode::F(12), InterfaceAction::ToggleDebugLayout);
+        }
+        if self.interface.debug_layout {
+            context.start_debug_overlay();
+        }
+
+        let in_game = self.interface.screen == Screen::Game;
+        let options =
+            ScreenOptions { side_panels: in_game, scale_map: in_game && self.settings.scale_map };
+        if let Some(screen) = ScreenLayout::new(area, options) {
+            for rect in screen.rects() {
+                context.record_layout(rect);
+            }
+            if let Some(outline) = screen.outline {
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(border::ROUNDED)
+                    .render(outline, buf);
+            }
+            let hovered_tile = self.render_game_area(screen.game, screen.scale, buf, context);
+            let panels = PanelData {
+                state: self.data,
+                interface: self.interface,
+                history: self.history,
+                hovered_tile,
+            };
+            if let Some(left) = screen.left_panel {
+                side_panel::render_column(&SidePanel::LEFT, panels, left, buf, context);
+            }
+            if let Some(right) = screen.right_panel {
+                side_panel::render_column(&SidePanel::RIGHT, panels, right, buf, context);
+            }
+            self.render_modals(screen.game, buf, context);
+        } else {
+            // The simulation is frozen until the game can be seen again, so
+            // leaving still needs confirmation.
+            let quit = if in_game {
+                InterfaceAction::ShowModal(Modal::Confirm(Confirmation::QuitGame))
+            } else {
+                InterfaceAction::Exit
+            };
+            context.key_pressed(KeyCode::Char('q'), quit);
+            let mut lines = vec![
                 Line::from(
                     "Error: The minimum terminal size for this game is 80 columns by 24 rows!",
                 ),
                 Line::from(format!("Your terminal is {} by {}.", area.width, area.height)),
-                Line::from("Press 'q' to quit."),
-            ])
-            .wrap(Wrap { trim: false })
-            .alignment(Alignment::Center)
-            .render(area, buf);
-        } else if area.width >= 82 && area.height >= 26 {
-            // Render an outline around the game area if there's room
-            let outline = layout::centered_rect(Size { width: 82, height: 26 }, area);
-            let block = Block::default().borders(Borders::ALL).border_set(border::ROUNDED);
-            let game_area = block.inner(outline);
-            block.render(outline, buf);
-            render_game_area(game_area, buf);
-        } else {
-            let game_area = layout::centered_rect(Size { width: 80, height: 24 }, area);
-            render_game_area(game_area, buf);
+            ];
+            if in_game {
+                lines.push(Line::from("The game is paused."));
+            }
+            lines.push(Line::from("Press 'q' to quit."));
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .alignment(Alignment::Center)
+                .render(area, buf);
+            self.render_modals(area, buf, context);
         }
+
+        if let Some(tooltip) = context.take_tooltip() {
+            tooltip.render(area, buf);
+        }
+
+        if self.interface.debug_layout {
+            context.render_debug_overlay(buf);
+        }
+        context.palette().apply_to_buffer(buf);
     }
 }
 
-fn render_game_area(area: Rect, buf: &mut Buffer) {
-    assert_eq!(area.width, 80);
-    assert_eq!(area.height, 24);
-    Block::default().borders(Borders::ALL).border_set(border::DOUBLE).render(area, buf);
+impl<'a> App<'a> {
+    /// Draws the current screen in `area`, returning the map tile under the
+    /// mouse cursor, if any.
+    fn render_game_area(
+        &self,
+        area: Rect,
+        scale: Scale,
+        buf: &mut Buffer,
+        context: &mut RenderContext,
+    ) -> Option<TilePosition> {
+        match self.interface.screen {
+            Screen::MainMenu => {
+                MainMenu.render(area, buf, context);
+                None
+            }
+            Screen::Game => {
+                let layout = GameLayout::new(area);
+                for rect in layout.rects() {
+                    context.record_layout(rect);
+                }
+                Hud { state: self.data, paused: self.interface.is_paused() }
+                    .render(layout.hud, buf, context);
+                let battlefield = Battlefield {
+                    state: self.data,
+                    effects: self.effects,
+                    scale,
+                    range_circles: self.settings.show_range_circles,
+                    health_bars: self.settings.show_health_bars,
+                    damage_numbers: self.settings.show_damage_numbers,
+                    tool: self.interface.tool,
+                    selected_tower: self.interface.selected_tower,
+                };
+                let hovered_tile = battlefield.hovered_tile(layout.battlefield, context);
+                battlefield.render(layout.battlefield, buf, context);
+                let selected = self.interface.selected_tower.and_then(|p| towers::at(self.data, p));
+                if let Some(tower) = selected {
+                    TowerPanel { state: self.data, tower }.render(layout.shop, buf, context);
+                } else {
+                    Shop { state: self.data, tool: self.interface.tool }.render(
+                        layout.shop,
+                        buf,
+                        context,
+                    );
+                }
+                StatusLine {
+                    state: self.data,
+                    tool: self.interface.tool,
+                    selected_tower: self.interface.selected_tower,
+                    hovered_tile,
+                    key_bindings: self.settings.key_bindings,
+                }
+                .render(layout.status, buf);
+                if self.interface.current_modal().is_none() {
+                    let bindings = self.settings.key_bindings;
+                    let pause = InterfaceAction::ShowModal(Modal::PauseMenu);
+                    let escape = if self.interface.tool != Tool::Select {
+                        InterfaceAction::SetTool(Tool::Select)
+                    } else if self.interface.selected_tower.is_some() {
+                        InterfaceAction::SelectTower(None)
+                    } else {
+                        pause
+                    };
+                    context.key_pressed(KeyCode::Esc, escape);
+                    context.key_pressed(bindings.pause, pause);
+                    context.key_pressed(
+                        bindings.change_speed,
+                        GameAction::SetSpeed(enum_iterator::next_cycle(&self.data.speed)),
+                    );
+                    context.key_pressed(
+                        bindings.quit,
+                        InterfaceAction::ShowModal(Modal::Confirm(Confirmation::QuitGame)),
+                    );
+                }
+                hovered_tile
+            }
+        }
+    }
+
+    /// Draws the topmost modal window, if any, centered in `area`.
+    fn render_modals(&self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        if self.interface.current_modal().is_some() {
+            context.modal_layer();
+        }
+
+        match self.interface.current_modal() {
+            Some(Modal::PauseMenu) => {
+                PauseMenu { key_bindings: self.settings.key_bindings }.render(area, buf, context)
+            }
+            Some(Modal::Settings) => {
+                SettingsMenu { settings: *self.settings }.render(area, buf, context)
+            }
+            Some(Modal::RebindKey(binding)) => {
+                RebindKeyPrompt { binding }.render(area, buf, context)
+            }
+            Some(Modal::Confirm(confirmation)) => {
+                ConfirmationDialog { confirmation }.render(area, buf, context)
+            }
+            None => {}
+        }
+    }
 }
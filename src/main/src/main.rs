@@ -15,26 +15,35 @@
 use std::env;
 
 use clap::Parser;
-use cli::Cli;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
+use data::core::{enemy, tower};
+use termdef::app::{AppState, TerminalEvents, TerminalSettings};
+use termdef::cli::Cli;
+use termdef::{app, settings_file, tui, utils};
 use tracing::info;
 
-pub mod app;
-pub mod cli;
-pub mod tui;
-pub mod utils;
-
-fn main() -> Result<()> {
+#[tokio::main]
+async fn main() -> Result<()> {
     utils::initialize_logging()?;
     if env::var("DISABLE_PANIC_HANDLER").is_err() {
         utils::initialize_panic_handler()?;
     }
     Cli::parse();
+    tower::validate_definitions()
+        .map_err(|(kind, e)| eyre!("Invalid upgrade tree for {kind:?}: {e}"))?;
+    enemy::validate_definitions()
+        .map_err(|(kind, e)| eyre!("Invalid abilities for {kind:?}: {e}"))?;
 
-    let mut tui = tui::enter()?;
+    let settings = settings_file::load();
+    let mut tui = tui::enter(&settings)?;
     let commit = env!("VERGEN_GIT_SHA");
     info!(commit, "Starting game");
-    app::run(&mut tui)?;
+    app::run(
+        &mut tui,
+        &mut TerminalEvents::new(),
+        &mut AppState::new(settings, TerminalSettings::new()),
+    )
+    .await?;
     tui::exit()?;
     Ok(())
 }
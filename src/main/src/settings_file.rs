@@ -0,0 +1,111 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::sync::mpsc::Sender;
+use std::thread::JoinHandle;
+use std::{fs, thread};
+
+use color_eyre::eyre::Result;
+use data::core::settings::Settings;
+use tracing::{error, info, warn};
+
+use crate::utils;
+
+/// Name of the file in the data directory which stores the user's settings.
+const SETTINGS_FILE: &str = "settings.toml";
+
+pub fn path() -> PathBuf {
+    utils::get_data_dir().join(SETTINGS_FILE)
+}
+
+/// Reads the user's settings from disk.
+///
+/// Returns the default settings if no settings file exists or if the file
+/// cannot be read.
+pub fn load() -> Settings {
+    let path = path();
+    if !path.exists() {
+        info!(?path, "No settings file found, using defaults");
+        return Settings::default();
+    }
+
+    match read(&path) {
+        Ok(settings) => settings,
+        Err(error) => {
+            warn!(?path, ?error, "Unable to read settings file, using defaults");
+            Settings::default()
+        }
+    }
+}
+
+/// Writes the provided settings to disk, replacing any previous settings.
+pub fn save(settings: &Settings) -> Result<()> {
+    let path = path();
+    fs::create_dir_all(utils::get_data_dir())?;
+    fs::write(&path, toml::to_string_pretty(settings)?)?;
+    info!(?path, "Saved settings");
+    Ok(())
+}
+
+/// Saves settings to disk on a background thread, one save at a time.
+///
+/// Saves are written in the order they were requested. If several are
+/// requested while a write is in progress, only the most recent is written
+/// next. Dropping the writer waits for any pending save to finish.
+pub struct SettingsWriter {
+    sender: Option<Sender<Settings>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl SettingsWriter {
+    pub fn spawn() -> Self {
+        let (sender, receiver) = mpsc::channel::<Settings>();
+        let thread = thread::spawn(move || {
+            while let Ok(mut settings) = receiver.recv() {
+                while let Ok(newer) = receiver.try_recv() {
+                    settings = newer;
+                }
+                if let Err(e) = save(&settings) {
+                    error!(?e, "Unable to save settings");
+                }
+            }
+        });
+        Self { sender: Some(sender), thread: Some(thread) }
+    }
+
+    /// Requests that `settings` be written to disk.
+    pub fn save(&self, settings: Settings) {
+        if let Some(sender) = &self.sender {
+            // The thread only exits once the sender is dropped
+            let _ = sender.send(settings);
+        }
+    }
+}
+
+impl Drop for SettingsWriter {
+    fn drop(&mut self) {
+        self.sender = None;
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                error!("Settings writer panicked");
+            }
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<Settings> {
+    Ok(toml::from_str(&fs::read_to_string(path)?)?)
+}
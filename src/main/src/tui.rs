@@ -21,20 +21,32 @@ use crossterm::event::{
     DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
 };
 use crossterm::terminal::{EnterAlternateScreen, LeaveAlternateScreen, SetTitle};
+use data::core::settings::Settings;
 use ratatui::prelude::CrosstermBackend;
 use ratatui::Terminal;
 
 pub type Tui = Terminal<CrosstermBackend<Stdout>>;
 
-pub fn enter() -> Result<Tui> {
+pub fn enter(settings: &Settings) -> Result<Tui> {
     crossterm::terminal::enable_raw_mode()?;
     crossterm::execute!(io::stdout(), EnterAlternateScreen, cursor::Hide)?;
-    crossterm::execute!(io::stdout(), EnableMouseCapture)?;
+    if settings.mouse_capture {
+        crossterm::execute!(io::stdout(), EnableMouseCapture)?;
+    }
     crossterm::execute!(io::stdout(), EnableBracketedPaste)?;
     crossterm::execute!(io::stdout(), SetTitle("termdef: Terminal Tower Defense"))?;
     Ok(Terminal::new(CrosstermBackend::new(io::stdout()))?)
 }
 
+pub fn set_mouse_capture(enabled: bool) -> Result<()> {
+    if enabled {
+        crossterm::execute!(io::stdout(), EnableMouseCapture)?;
+    } else {
+        crossterm::execute!(io::stdout(), DisableMouseCapture)?;
+    }
+    Ok(())
+}
+
 pub fn exit() -> Result<()> {
     if crossterm::terminal::is_raw_mode_enabled()? {
         crossterm::execute!(io::stdout(), DisableBracketedPaste)?;
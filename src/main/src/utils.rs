@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std::panic;
-use std::panic::PanicInfo;
+use std::panic::PanicHookInfo;
 use std::path::PathBuf;
 
 use color_eyre::config::{HookBuilder, PanicHook};
@@ -64,7 +64,7 @@ pub fn initialize_panic_handler() -> Result<()> {
     Ok(())
 }
 
-fn on_panic(panic_hook: &PanicHook, panic_info: &PanicInfo) {
+fn on_panic(panic_hook: &PanicHook, panic_info: &PanicHookInfo) {
     if let Err(r) = tui::exit() {
         error!("Unable to exit Terminal: {:?}", r);
     }
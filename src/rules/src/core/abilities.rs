@@ -0,0 +1,91 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::enemy::{AbilityEffect, AbilityTrigger, EnemyId};
+use data::core::game_state::GameState;
+
+use crate::core::enemies;
+use crate::core::towers::within_range;
+
+/// Triggers the abilities of every enemy whose conditions are met on this
+/// tick.
+pub fn run(state: &mut GameState) {
+    let mut triggered = vec![];
+    for enemy in &mut state.enemies {
+        let abilities = enemy.definition().abilities;
+        for ability in abilities {
+            if let AbilityTrigger::Every(ticks) = ability.trigger {
+                if enemy.age > 0 && enemy.age % ticks == 0 {
+                    triggered.push((enemy.id, ability.effect));
+                }
+            }
+        }
+
+        let thresholds = abilities.iter().filter_map(|a| match a.trigger {
+            AbilityTrigger::HealthBelow(percent) => Some((percent, a.effect)),
+            AbilityTrigger::Every(_) => None,
+        });
+        for (percent, effect) in thresholds.skip(enemy.phase) {
+            if enemy.health as u64 * 100 > percent as u64 * enemy.max_health as u64 {
+                break;
+            }
+            enemy.phase += 1;
+            triggered.push((enemy.id, effect));
+        }
+    }
+
+    for (id, effect) in triggered {
+        apply(state, id, effect);
+    }
+}
+
+fn apply(state: &mut GameState, source: EnemyId, effect: AbilityEffect) {
+    let Some(enemy) = state.enemies.iter_mut().find(|e| e.id == source) else {
+        return;
+    };
+    let distance = enemy.distance;
+    match effect {
+        AbilityEffect::SpawnMinions { kind, count } => {
+            for _ in 0..count {
+                enemies::spawn(state, kind, distance);
+            }
+        }
+        AbilityEffect::DisableTowers { range, ticks } => {
+            let Some(position) = enemy.position(&state.map) else {
+                return;
+            };
+            let until = state.tick + ticks;
+            for tower in &mut state.towers {
+                if within_range(position, tower.position, range) {
+                    tower.disabled_until = tower.disabled_until.max(until);
+                }
+            }
+        }
+        AbilityEffect::HealAllies { range, amount } => {
+            let Some(position) = enemy.position(&state.map) else {
+                return;
+            };
+            for ally in &mut state.enemies {
+                let nearby =
+                    ally.position(&state.map).is_some_and(|p| within_range(position, p, range));
+                if ally.id != source && nearby {
+                    ally.health = ally.health.saturating_add(amount).min(ally.max_health);
+                }
+            }
+        }
+        AbilityEffect::SpeedUp(amount) => {
+            enemy.speed += amount;
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::game_state::GameState;
+
+/// Removes the projectiles fired on the previous tick and ages every effect,
+/// removing those which have expired.
+pub fn advance(state: &mut GameState) {
+    state.projectiles.clear();
+    for effect in &mut state.effects {
+        effect.remaining_ticks = effect.remaining_ticks.saturating_sub(1);
+    }
+    state.effects.retain(|e| e.remaining_ticks > 0);
+}
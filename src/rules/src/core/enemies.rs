@@ -0,0 +1,47 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::enemy::{Enemy, EnemyId, EnemyKind};
+use data::core::game_event::GameEvent;
+use data::core::game_state::GameState;
+
+/// Adds a new enemy with full health at `distance` along the path.
+pub fn spawn(state: &mut GameState, kind: EnemyKind, distance: u32) -> EnemyId {
+    let id = state.next_enemy_id;
+    state.next_enemy_id = EnemyId(id.0 + 1);
+    state.enemies.push(Enemy::new(id, kind, distance));
+    id
+}
+
+/// Moves every enemy along its route by its speed. Enemies which reach the
+/// end of the path are removed and cost the player a life each.
+pub fn advance(state: &mut GameState) {
+    for enemy in &mut state.enemies {
+        enemy.distance += enemy.speed;
+        enemy.age += 1;
+    }
+
+    let map = &state.map;
+    let events = &mut state.events;
+    let before = state.enemies.len();
+    state.enemies.retain(|e| {
+        let on_path = e.position(map).is_some();
+        if !on_path {
+            events.push(GameEvent::EnemyEscaped { kind: e.kind });
+        }
+        on_path
+    });
+    let escaped = (before - state.enemies.len()) as u32;
+    state.lives = state.lives.saturating_sub(escaped);
+}
@@ -15,4 +15,27 @@
 use data::core::actions::GameAction;
 use data::core::game_state::GameState;
 
-pub fn handle_game_action(_state: &mut GameState, _action: GameAction) {}
+use crate::core::{towers, waves};
+
+pub fn handle_game_action(state: &mut GameState, action: GameAction) {
+    match action {
+        GameAction::SetSpeed(speed) => {
+            state.speed = speed;
+        }
+        GameAction::BuildTower(kind, position) => {
+            towers::build(state, kind, position);
+        }
+        GameAction::UpgradeTower(position, upgrade) => {
+            towers::upgrade(state, position, upgrade);
+        }
+        GameAction::SellTower(position) => {
+            towers::sell(state, position);
+        }
+        GameAction::SetTargeting(position, mode) => {
+            towers::set_targeting(state, position, mode);
+        }
+        GameAction::SendWaveEarly => {
+            waves::send_early(state);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use data::core::map::{Map, Terrain, TilePosition, MAP_HEIGHT, MAP_WIDTH};
+
+/// Corners of the path on the default map, from entrance to exit.
+const DEFAULT_PATH: [(u16, u16); 8] =
+    [(0, 3), (14, 3), (14, 17), (33, 17), (33, 5), (48, 5), (48, 12), (59, 12)];
+
+/// Areas of non-grass terrain on the default map, as (x, y, width, height).
+const DEFAULT_TERRAIN: [(Terrain, u16, u16, u16, u16); 6] = [
+    (Terrain::Water, 20, 6, 8, 4),
+    (Terrain::Water, 52, 16, 6, 4),
+    (Terrain::Forest, 2, 10, 9, 6),
+    (Terrain::Forest, 38, 0, 16, 3),
+    (Terrain::Rock, 22, 19, 5, 2),
+    (Terrain::Rock, 40, 9, 3, 6),
+];
+
+/// Creates the map used for new games.
+pub fn default_map() -> Map {
+    let mut map = Map::new();
+    for (terrain, x, y, width, height) in DEFAULT_TERRAIN {
+        for y in y..(y + height).min(MAP_HEIGHT) {
+            for x in x..(x + width).min(MAP_WIDTH) {
+                map.set_terrain(TilePosition::new(x, y), terrain);
+            }
+        }
+    }
+
+    map.path = vec![TilePosition::new(DEFAULT_PATH[0].0, DEFAULT_PATH[0].1)];
+    for &(x, y) in &DEFAULT_PATH[1..] {
+        let target = TilePosition::new(x, y);
+        while let Some(&current) = map.path.last().filter(|p| **p != target) {
+            let next = TilePosition::new(step(current.x, target.x), step(current.y, target.y));
+            map.path.push(next);
+        }
+    }
+
+    for position in map.path.clone() {
+        map.set_terrain(position, Terrain::Grass);
+    }
+    map
+}
+
+/// Moves one unit from `from` towards `to`.
+fn step(from: u16, to: u16) -> u16 {
+    match from.cmp(&to) {
+        Ordering::Less => from + 1,
+        Ordering::Equal => from,
+        Ordering::Greater => from - 1,
+    }
+}
@@ -12,5 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod abilities;
+pub mod effects;
+pub mod enemies;
 pub mod handle_action;
+pub mod maps;
 pub mod new_game;
+pub mod tick;
+pub mod towers;
+pub mod waves;
@@ -12,8 +12,43 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use data::core::game_state::GameState;
+use data::core::enemy::EnemyId;
+use data::core::game_event::GameEvents;
+use data::core::game_state::{GameSpeed, GameState};
+use data::core::tower::TowerKind;
 
-pub fn create() -> GameState {
-    GameState {}
+use crate::core::{maps, waves};
+
+/// Amount of gold the player has at the start of a game.
+pub const STARTING_GOLD: u32 = 150;
+
+/// Number of lives the player has at the start of a game.
+pub const STARTING_LIVES: u32 = 20;
+
+/// Number of waves which must be survived to win.
+pub const TOTAL_WAVES: u32 = 10;
+
+/// Number of ticks before the first wave starts.
+pub const FIRST_WAVE_DELAY: u64 = 600;
+
+pub fn create(speed: GameSpeed) -> GameState {
+    GameState {
+        tick: 0,
+        speed,
+        gold: STARTING_GOLD,
+        lives: STARTING_LIVES,
+        score: 0,
+        wave: 0,
+        waves: waves::default_waves(TOTAL_WAVES),
+        next_wave_tick: Some(FIRST_WAVE_DELAY),
+        pending_spawns: vec![],
+        map: maps::default_map(),
+        towers: vec![],
+        unlocked_towers: enum_iterator::all::<TowerKind>().collect(),
+        enemies: vec![],
+        next_enemy_id: EnemyId(1),
+        projectiles: vec![],
+        effects: vec![],
+        events: GameEvents::default(),
+    }
 }
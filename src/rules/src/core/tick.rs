@@ -0,0 +1,30 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::game_state::GameState;
+
+use crate::core::{abilities, effects, enemies, towers, waves};
+
+/// Advances the game simulation by a single tick.
+pub fn run(state: &mut GameState) {
+    state.tick += 1;
+    if state.next_wave_tick.is_some_and(|t| state.tick >= t) {
+        waves::start_next_wave(state);
+    }
+    enemies::advance(state);
+    waves::spawn_pending(state);
+    effects::advance(state);
+    towers::attack(state);
+    abilities::run(state);
+}
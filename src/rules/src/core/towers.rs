@@ -0,0 +1,205 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Reverse;
+
+use data::core::enemy::{Enemy, EnemyTrait, ARMOR};
+use data::core::game_event::GameEvent;
+use data::core::game_state::GameState;
+use data::core::map::{Terrain, TilePosition, MAP_HEIGHT, MAP_WIDTH};
+use data::core::projectile::{Effect, EffectKind, Projectile};
+use data::core::tower::{TargetingMode, Tower, TowerKind};
+use data::core::upgrades::TowerStats;
+use tracing::info;
+
+/// True if a tower could be placed at `position`, ignoring its cost.
+///
+/// Towers can only be built on empty grass away from the path.
+pub fn can_build(state: &GameState, position: TilePosition) -> bool {
+    position.x < MAP_WIDTH
+        && position.y < MAP_HEIGHT
+        && state.map.terrain(position) == Terrain::Grass
+        && !state.map.is_path(position)
+        && !state.towers.iter().any(|t| t.position == position)
+}
+
+/// True if the player has unlocked and can afford a tower of the given kind.
+pub fn can_afford(state: &GameState, kind: TowerKind) -> bool {
+    state.unlocked_towers.contains(&kind) && state.gold >= kind.definition().cost
+}
+
+/// Builds a new tower, spending its cost. Does nothing if the tower cannot
+/// be built there or the player cannot afford it.
+pub fn build(state: &mut GameState, kind: TowerKind, position: TilePosition) {
+    if !can_afford(state, kind) || !can_build(state, position) {
+        info!(?kind, ?position, "Unable to build tower");
+        return;
+    }
+    state.gold -= kind.definition().cost;
+    state.towers.push(Tower::new(kind, position));
+    state.events.push(GameEvent::TowerBuilt { kind, position });
+}
+
+/// Fraction of the gold invested in a tower which is refunded when it is
+/// sold, in percent.
+pub const SELL_REFUND_PERCENT: u32 = 75;
+
+/// Tower at the given position, if any.
+pub fn at(state: &GameState, position: TilePosition) -> Option<&Tower> {
+    state.towers.iter().find(|t| t.position == position)
+}
+
+/// Amount of gold received for selling a tower.
+pub fn sell_value(tower: &Tower) -> u32 {
+    tower.invested * SELL_REFUND_PERCENT / 100
+}
+
+/// True if the upgrade with the given index is available for the tower and
+/// the player can afford it.
+pub fn can_upgrade(state: &GameState, tower: &Tower, upgrade: usize) -> bool {
+    tower.available_upgrades().contains(&upgrade)
+        && state.gold >= tower.definition().upgrades.nodes[upgrade].cost
+}
+
+/// Purchases an upgrade for the tower at `position`. Does nothing if there is
+/// no tower, the upgrade is not available, e.g. because a different branch
+/// was chosen, or the player cannot afford it.
+pub fn upgrade(state: &mut GameState, position: TilePosition, upgrade: usize) {
+    let Some(index) = state.towers.iter().position(|t| t.position == position) else {
+        return;
+    };
+    if !can_upgrade(state, &state.towers[index], upgrade) {
+        info!(?position, upgrade, "Unable to upgrade tower");
+        return;
+    }
+    let tower = &mut state.towers[index];
+    let cost = tower.definition().upgrades.nodes[upgrade].cost;
+    tower.upgrades.push(upgrade);
+    tower.invested += cost;
+    state.gold -= cost;
+}
+
+/// Removes the tower at `position` and refunds [sell_value] gold.
+pub fn sell(state: &mut GameState, position: TilePosition) {
+    let Some(index) = state.towers.iter().position(|t| t.position == position) else {
+        return;
+    };
+    let tower = state.towers.remove(index);
+    let refund = sell_value(&tower);
+    state.gold += refund;
+    state.events.push(GameEvent::TowerSold { kind: tower.kind, position, refund });
+}
+
+pub fn set_targeting(state: &mut GameState, position: TilePosition, mode: TargetingMode) {
+    if let Some(tower) = state.towers.iter_mut().find(|t| t.position == position) {
+        tower.targeting = mode;
+    }
+}
+
+/// Fires every tower which has reloaded and is not disabled at the enemies in
+/// range chosen by its [TargetingMode].
+///
+/// Each shot adds a [Projectile] and, for towers with an [impact], an
+/// [Effect] at the target. Enemies reduced to zero health are removed,
+/// awarding their bounty in gold and score. Each hit and defeat is recorded
+/// in [GameState::events].
+pub fn attack(state: &mut GameState) {
+    for index in 0..state.towers.len() {
+        let tower = &state.towers[index];
+        if tower.is_disabled(state.tick) || state.tick < tower.ready_at {
+            continue;
+        }
+        let stats = tower.stats();
+        let targets = targets(state, tower, stats);
+        if targets.is_empty() {
+            continue;
+        }
+
+        let tower = &mut state.towers[index];
+        tower.ready_at = state.tick + stats.reload as u64;
+        for target in targets {
+            let enemy = &mut state.enemies[target];
+            let Some(position) = enemy.position(&state.map) else {
+                continue;
+            };
+            let midpoint = TilePosition::new(
+                (tower.position.x + position.x) / 2,
+                (tower.position.y + position.y) / 2,
+            );
+            state.projectiles.push(Projectile { source: tower.kind, position: midpoint });
+            if let Some(kind) = impact(tower.kind) {
+                state.effects.push(Effect { kind, position, remaining_ticks: kind.duration() });
+            }
+            let damage = damage_taken(enemy, stats.damage).min(enemy.health);
+            enemy.health -= damage;
+            tower.damage_dealt += damage;
+            state.events.push(GameEvent::EnemyDamaged { position, amount: damage });
+            if enemy.health == 0 {
+                let bounty = enemy.definition().bounty;
+                tower.kills += 1;
+                state.gold += bounty;
+                state.score += bounty;
+                state.events.push(GameEvent::EnemyDefeated { kind: enemy.kind, position, bounty });
+            }
+        }
+    }
+    state.enemies.retain(|e| e.health > 0);
+}
+
+/// Effect left on the target tile by each shot from a tower of this kind.
+pub fn impact(kind: TowerKind) -> Option<EffectKind> {
+    match kind {
+        TowerKind::Arrow => None,
+        TowerKind::Cannon => Some(EffectKind::Explosion),
+        TowerKind::Frost => Some(EffectKind::Frost),
+    }
+}
+
+/// Indices in [GameState::enemies] of the living enemies a tower would
+/// attack, best target first.
+fn targets(state: &GameState, tower: &Tower, stats: TowerStats) -> Vec<usize> {
+    let mut in_range = state
+        .enemies
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| e.health > 0)
+        .filter(|(_, e)| {
+            e.position(&state.map).is_some_and(|p| within_range(tower.position, p, stats.range))
+        })
+        .collect::<Vec<_>>();
+    match tower.targeting {
+        TargetingMode::First => in_range.sort_by_key(|(_, e)| Reverse(e.distance)),
+        TargetingMode::Last => in_range.sort_by_key(|(_, e)| e.distance),
+        TargetingMode::Strongest => in_range.sort_by_key(|(_, e)| Reverse(e.health)),
+        TargetingMode::Weakest => in_range.sort_by_key(|(_, e)| e.health),
+    }
+    in_range.into_iter().take(stats.targets as usize).map(|(i, _)| i).collect()
+}
+
+/// Damage an enemy takes from a hit of `damage`, before being limited to its
+/// remaining health.
+pub fn damage_taken(enemy: &Enemy, damage: u32) -> u32 {
+    if enemy.has_trait(EnemyTrait::Armored) {
+        damage.saturating_sub(ARMOR).max(1)
+    } else {
+        damage
+    }
+}
+
+/// True if the tiles are no more than `range` tiles apart.
+pub fn within_range(a: TilePosition, b: TilePosition, range: u32) -> bool {
+    let dx = a.x.abs_diff(b.x) as u32;
+    let dy = a.y.abs_diff(b.y) as u32;
+    dx * dx + dy * dy <= range * range
+}
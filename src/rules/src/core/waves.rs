@@ -0,0 +1,111 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::enemy::EnemyKind;
+use data::core::game_event::GameEvent;
+use data::core::game_state::GameState;
+use data::core::wave::{PendingSpawn, Wave, WaveGroup};
+use tracing::info;
+
+use crate::core::enemies;
+
+/// Number of ticks between the start of one wave and the next.
+pub const WAVE_INTERVAL: u64 = 900;
+
+/// Number of ticks of waiting skipped by sending a wave early which earn
+/// one gold.
+pub const EARLY_BONUS_TICKS_PER_GOLD: u64 = 20;
+
+/// Number of ticks between successive enemies of a wave entering the map.
+pub const SPAWN_SPACING: u64 = 10;
+
+/// Creates the schedule of `count` waves used for new games, growing
+/// steadily larger and ending with a boss.
+pub fn default_waves(count: u32) -> Vec<Wave> {
+    (1..=count)
+        .map(|n| {
+            let mut groups = vec![group(EnemyKind::Grunt, 4 + 2 * n)];
+            if n >= 3 {
+                groups.push(group(EnemyKind::Runner, n));
+            }
+            if n % 2 == 0 {
+                groups.push(group(EnemyKind::Bat, n / 2));
+            }
+            if n % 3 == 0 {
+                groups.push(group(EnemyKind::Brute, n / 3));
+            }
+            if n == count {
+                groups.push(group(EnemyKind::Warlord, 1));
+            }
+            Wave { groups }
+        })
+        .collect()
+}
+
+/// Begins the next wave and schedules the one after it, if any.
+///
+/// The wave's enemies are queued to enter the map one at a time, group by
+/// group, [SPAWN_SPACING] ticks apart, following any enemies still waiting
+/// from earlier waves.
+pub fn start_next_wave(state: &mut GameState) {
+    let Some(wave) = state.waves.get(state.wave as usize) else {
+        return;
+    };
+    let mut tick = state.pending_spawns.last().map_or(state.tick, |s| s.tick + SPAWN_SPACING);
+    for group in &wave.groups {
+        for _ in 0..group.count {
+            state.pending_spawns.push(PendingSpawn {
+                enemy: group.enemy,
+                spawn_point: group.spawn_point,
+                tick,
+            });
+            tick += SPAWN_SPACING;
+        }
+    }
+
+    state.wave += 1;
+    state.events.push(GameEvent::WaveStarted { wave: state.wave });
+    state.next_wave_tick = (state.wave < state.total_waves()).then_some(state.tick + WAVE_INTERVAL);
+}
+
+/// Adds every queued enemy which is due on the current tick to the map at
+/// its spawn point.
+pub fn spawn_pending(state: &mut GameState) {
+    let due = state.pending_spawns.iter().take_while(|s| s.tick <= state.tick).count();
+    for spawn in state.pending_spawns.drain(..due).collect::<Vec<_>>() {
+        let distance = state.map.spawn_distance(spawn.spawn_point);
+        enemies::spawn(state, spawn.enemy, distance);
+    }
+}
+
+/// Gold awarded for starting the next wave immediately, or `None` if there
+/// is no next wave.
+pub fn early_bonus(state: &GameState) -> Option<u32> {
+    state.ticks_until_next_wave().map(|ticks| (ticks / EARLY_BONUS_TICKS_PER_GOLD) as u32)
+}
+
+/// Starts the next wave immediately, awarding [early_bonus] gold.
+pub fn send_early(state: &mut GameState) {
+    let Some(bonus) = early_bonus(state) else {
+        info!("No wave to send early");
+        return;
+    };
+    state.gold += bonus;
+    state.events.push(GameEvent::WaveSentEarly { bonus });
+    start_next_wave(state);
+}
+
+fn group(enemy: EnemyKind, count: u32) -> WaveGroup {
+    WaveGroup { enemy, count, spawn_point: 0 }
+}
@@ -0,0 +1,206 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crossterm::event::{Event, KeyCode};
+use data::core::game_state::TICK_DURATION;
+use data::core::settings::Settings;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::Terminal;
+use termdef::app::{self, AppState, EventSource, SettingsSink};
+use tokio::runtime::{self, Runtime};
+use tokio::time::{self, Instant};
+
+use crate::{events, screen};
+
+/// Runs the game's main loop against an in-memory terminal, feeding it a
+/// scripted sequence of input events.
+///
+/// Each method queues its input and then runs the main loop until all queued
+/// input has been handled. Time is simulated and only advances via
+/// [Self::wait], so tests run instantly and deterministically.
+pub struct TestDriver {
+    runtime: Runtime,
+    terminal: Terminal<TestBackend>,
+    events: ScriptedEvents,
+    saved_settings: Rc<Cell<Option<Settings>>>,
+    pub state: AppState,
+}
+
+impl Default for TestDriver {
+    fn default() -> Self {
+        Self::new(80, 24)
+    }
+}
+
+impl TestDriver {
+    pub fn new(width: u16, height: u16) -> Self {
+        let runtime = runtime::Builder::new_current_thread()
+            .enable_time()
+            .start_paused(true)
+            .build()
+            .expect("Error creating runtime");
+        let saved_settings = Rc::new(Cell::new(None));
+        let sink = RecordedSettings(saved_settings.clone());
+        let state = runtime.block_on(async { AppState::new(Settings::default(), sink) });
+        let mut result = Self {
+            runtime,
+            terminal: Terminal::new(TestBackend::new(width, height))
+                .expect("Error creating test terminal"),
+            events: ScriptedEvents { steps: VecDeque::new(), deadline: None },
+            saved_settings,
+            state,
+        };
+        result.run();
+        result
+    }
+
+    /// Most recent settings which the game would have saved to disk.
+    pub fn saved_settings(&self) -> Option<Settings> {
+        self.saved_settings.get()
+    }
+
+    /// Contents of the terminal after the most recent frame.
+    pub fn buffer(&self) -> &Buffer {
+        self.terminal.backend().buffer()
+    }
+
+    /// Sends a single event to the main loop.
+    pub fn send(&mut self, event: Event) -> &mut Self {
+        self.events.steps.push_back(Step::Event(event));
+        self.run()
+    }
+
+    /// Presses the given key.
+    pub fn key(&mut self, code: KeyCode) -> &mut Self {
+        self.send(events::key_press(code))
+    }
+
+    /// Presses each of the given keys in quick succession, without waiting
+    /// for the main loop to handle one before sending the next.
+    pub fn keys(&mut self, codes: &[KeyCode]) -> &mut Self {
+        for &code in codes {
+            self.events.steps.push_back(Step::Event(events::key_press(code)));
+        }
+        self.run()
+    }
+
+    /// Changes the size of the terminal.
+    pub fn resize(&mut self, width: u16, height: u16) -> &mut Self {
+        self.terminal.backend_mut().resize(width, height);
+        self.send(Event::Resize(width, height))
+    }
+
+    /// Moves the mouse to the given position, then presses and releases the
+    /// left mouse button.
+    pub fn click_at(&mut self, column: u16, row: u16) -> &mut Self {
+        self.events.steps.push_back(Step::Event(events::mouse_moved(column, row)));
+        self.events.steps.push_back(Step::Event(events::mouse_down(column, row)));
+        self.events.steps.push_back(Step::Event(events::mouse_up(column, row)));
+        self.run()
+    }
+
+    /// Clicks on the center of the first occurrence of `text` on screen.
+    ///
+    /// Panics if the text is not currently displayed.
+    pub fn click(&mut self, text: &str) -> &mut Self {
+        let Some((column, row)) = self.find(text) else {
+            panic!("Text {text:?} not found on screen:\n{:?}", self.buffer());
+        };
+        self.click_at(column + text.chars().count() as u16 / 2, row)
+    }
+
+    /// Returns the position of the first character of `text` on screen, if it
+    /// is displayed.
+    pub fn find(&self, text: &str) -> Option<(u16, u16)> {
+        screen::find(self.buffer(), text)
+    }
+
+    /// True if `text` is currently displayed.
+    pub fn displays(&self, text: &str) -> bool {
+        self.find(text).is_some()
+    }
+
+    /// Advances time by the duration of the given number of simulation ticks
+    /// at normal speed.
+    pub fn wait_ticks(&mut self, ticks: u32) -> &mut Self {
+        self.wait(TICK_DURATION * ticks)
+    }
+
+    /// Advances time by the given duration.
+    pub fn wait(&mut self, duration: Duration) -> &mut Self {
+        self.events.steps.push_back(Step::Wait(duration));
+        self.run()
+    }
+
+    /// Advances time by the given duration without running the main loop,
+    /// as if the process had been suspended.
+    pub fn stall(&mut self, duration: Duration) -> &mut Self {
+        self.runtime.block_on(time::advance(duration));
+        self
+    }
+
+    fn run(&mut self) -> &mut Self {
+        self.runtime
+            .block_on(app::run(&mut self.terminal, &mut self.events, &mut self.state))
+            .expect("Error running main loop");
+        self
+    }
+}
+
+/// [SettingsSink] which records the latest settings instead of touching the
+/// real terminal or settings file.
+struct RecordedSettings(Rc<Cell<Option<Settings>>>);
+
+impl SettingsSink for RecordedSettings {
+    fn settings_changed(&mut self, _: Settings, new: Settings) {
+        self.0.set(Some(new));
+    }
+}
+
+enum Step {
+    Event(Event),
+    Wait(Duration),
+}
+
+/// [EventSource] which replays a queue of events, sleeping for the requested
+/// duration between them.
+struct ScriptedEvents {
+    steps: VecDeque<Step>,
+
+    /// Time at which the current [Step::Wait] completes.
+    deadline: Option<Instant>,
+}
+
+impl EventSource for ScriptedEvents {
+    async fn next(&mut self) -> color_eyre::Result<Option<Event>> {
+        loop {
+            if let Some(deadline) = self.deadline {
+                time::sleep_until(deadline).await;
+                self.deadline = None;
+            }
+
+            match self.steps.pop_front() {
+                Some(Step::Event(event)) => return Ok(Some(event)),
+                Some(Step::Wait(duration)) => self.deadline = Some(Instant::now() + duration),
+                None => return Ok(None),
+            }
+        }
+    }
+}
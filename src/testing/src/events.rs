@@ -0,0 +1,62 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Constructors for synthetic terminal input events.
+
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
+
+pub fn mouse_moved(column: u16, row: u16) -> Event {
+    mouse(MouseEventKind::Moved, column, row)
+}
+
+pub fn mouse_down(column: u16, row: u16) -> Event {
+    mouse(MouseEventKind::Down(MouseButton::Left), column, row)
+}
+
+pub fn mouse_drag(column: u16, row: u16) -> Event {
+    mouse(MouseEventKind::Drag(MouseButton::Left), column, row)
+}
+
+pub fn mouse_up(column: u16, row: u16) -> Event {
+    mouse(MouseEventKind::Up(MouseButton::Left), column, row)
+}
+
+pub fn scroll_down(column: u16, row: u16) -> Event {
+    mouse(MouseEventKind::ScrollDown, column, row)
+}
+
+pub fn scroll_up(column: u16, row: u16) -> Event {
+    mouse(MouseEventKind::ScrollUp, column, row)
+}
+
+pub fn key_press(code: KeyCode) -> Event {
+    key_press_with(code, KeyModifiers::NONE)
+}
+
+/// Press of a key while the given modifier keys are held.
+pub fn key_press_with(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent { code, modifiers, kind: KeyEventKind::Press, state: KeyEventState::NONE })
+}
+
+/// Text pasted into the terminal while bracketed paste is enabled.
+pub fn paste(text: &str) -> Event {
+    Event::Paste(text.to_string())
+}
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent { kind, column, row, modifiers: KeyModifiers::NONE })
+}
@@ -0,0 +1,183 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{Duration, Instant};
+
+use crossterm::event::{Event, KeyCode};
+use data::core::actions::InterfaceAction;
+use display::core::render_context::RenderContext;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+use ratatui::Terminal;
+
+use crate::{events, screen};
+
+/// Renders widgets into an in-memory terminal and delivers synthetic input
+/// events to them.
+pub struct TestHarness {
+    terminal: Terminal<TestBackend>,
+    context: RenderContext,
+    widget_area: Option<Rect>,
+    now: Instant,
+}
+
+impl TestHarness {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            terminal: Terminal::new(TestBackend::new(width, height))
+                .expect("Error creating test terminal"),
+            context: RenderContext::default(),
+            widget_area: None,
+            now: Instant::now(),
+        }
+    }
+
+    /// Renders widgets into the given area instead of filling the terminal.
+    pub fn with_widget_area(mut self, area: Rect) -> Self {
+        self.widget_area = Some(area);
+        self
+    }
+
+    pub fn context(&mut self) -> &mut RenderContext {
+        &mut self.context
+    }
+
+    /// Contents of the terminal after the most recent render.
+    pub fn buffer(&self) -> &Buffer {
+        self.terminal.backend().buffer()
+    }
+
+    /// Returns a text representation of the terminal's contents and styles,
+    /// suitable for comparison against a stored snapshot.
+    pub fn snapshot(&self) -> String {
+        format!("{:?}", self.buffer())
+    }
+
+    /// Time reported to widgets during the next render.
+    pub fn now(&self) -> Instant {
+        self.now
+    }
+
+    /// Advances the time reported to widgets by `duration`. No real time
+    /// passes.
+    pub fn advance(&mut self, duration: Duration) {
+        self.now += duration;
+    }
+
+    /// True if `text` is displayed after the most recent render.
+    pub fn displays(&self, text: &str) -> bool {
+        screen::find(self.buffer(), text).is_some()
+    }
+
+    /// Queues an event to be delivered to widgets during the next call to
+    /// [Self::render].
+    pub fn send(&mut self, event: Event) {
+        self.context.push_event(event);
+    }
+
+    /// Renders the widget returned by `widget` to fill the terminal or the
+    /// area given to [Self::with_widget_area].
+    ///
+    /// Hover, mouse down and scroll state changes are applied to the
+    /// [RenderContext] immediately and the widget is rendered again to
+    /// display them. Rendering repeats until all queued events have been
+    /// delivered. All other actions produced by the widget are returned in
+    /// order.
+    pub fn render<W>(&mut self, mut widget: impl FnMut() -> W) -> Vec<InterfaceAction>
+    where
+        W: StatefulWidget<State = RenderContext>,
+    {
+        let mut result = vec![];
+        loop {
+            let context = &mut self.context;
+            let widget_area = self.widget_area;
+            let mut actions = vec![];
+            context.start_frame(self.now);
+            self.terminal
+                .draw(|frame| {
+                    let area = widget_area.unwrap_or(frame.size());
+                    frame.render_stateful_widget(widget(), area, context);
+                    if let Some(tooltip) = context.take_tooltip() {
+                        frame.render_widget(tooltip, frame.size());
+                    }
+                    actions = context.finish_render();
+                })
+                .expect("Error rendering to test terminal");
+
+            let mut changed = false;
+            for action in actions {
+                match action {
+                    InterfaceAction::SetHover(id) => self.context.set_current_hover(id),
+                    InterfaceAction::SetMouseDown(id) => self.context.set_current_mouse_down(id),
+                    InterfaceAction::SetScrollOffset(id, offset) => {
+                        self.context.set_scroll_offset(id, offset)
+                    }
+                    _ => {
+                        result.push(action);
+                        continue;
+                    }
+                }
+                changed = true;
+            }
+
+            if !changed && !self.context.has_queued_events() {
+                return result;
+            }
+        }
+    }
+
+    /// Moves the mouse to the given position and renders.
+    pub fn hover<W>(
+        &mut self,
+        column: u16,
+        row: u16,
+        widget: impl FnMut() -> W,
+    ) -> Vec<InterfaceAction>
+    where
+        W: StatefulWidget<State = RenderContext>,
+    {
+        self.send(events::mouse_moved(column, row));
+        self.render(widget)
+    }
+
+    /// Presses and releases the left mouse button at the given position,
+    /// rendering after each event.
+    ///
+    /// Returns the actions produced when the button is released.
+    pub fn click<W>(
+        &mut self,
+        column: u16,
+        row: u16,
+        mut widget: impl FnMut() -> W,
+    ) -> Vec<InterfaceAction>
+    where
+        W: StatefulWidget<State = RenderContext>,
+    {
+        self.send(events::mouse_down(column, row));
+        self.render(&mut widget);
+        self.send(events::mouse_up(column, row));
+        self.render(widget)
+    }
+
+    /// Presses the given key and renders.
+    pub fn key<W>(&mut self, code: KeyCode, widget: impl FnMut() -> W) -> Vec<InterfaceAction>
+    where
+        W: StatefulWidget<State = RenderContext>,
+    {
+        self.send(events::key_press(code));
+        self.render(widget)
+    }
+}
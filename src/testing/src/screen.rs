@@ -0,0 +1,29 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ratatui::buffer::Buffer;
+
+/// Returns the position of the first character of `text` in `buffer`, if it
+/// is displayed.
+pub fn find(buffer: &Buffer, text: &str) -> Option<(u16, u16)> {
+    (0..buffer.area.height).find_map(|row| {
+        let line = (0..buffer.area.width)
+            .map(|column| buffer.get(column, row).symbol())
+            .collect::<Vec<_>>();
+        let needle = text.chars().map(|c| c.to_string()).collect::<Vec<_>>();
+        line.windows(needle.len())
+            .position(|window| window.iter().zip(&needle).all(|(a, b)| *a == b))
+            .map(|column| (column as u16, row))
+    })
+}
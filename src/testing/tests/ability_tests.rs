@@ -0,0 +1,249 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::enemy;
+use data::core::enemy::{Ability, AbilityEffect, AbilityError, AbilityTrigger, EnemyId, EnemyKind};
+use data::core::game_state::GameState;
+use data::core::map::{TilePosition, PATH_UNITS_PER_TILE};
+use data::core::tower::{Tower, TowerKind};
+use rules::core::new_game::{self, STARTING_LIVES};
+use rules::core::{abilities, enemies, tick, towers};
+
+/// Creates a game with a Warlord five tiles along the path.
+fn boss_fight() -> (GameState, EnemyId) {
+    let mut state = new_game::create(Default::default());
+    state.next_wave_tick = None;
+    let id = enemies::spawn(&mut state, EnemyKind::Warlord, 5 * PATH_UNITS_PER_TILE);
+    (state, id)
+}
+
+fn set_health_percent(state: &mut GameState, id: EnemyId, percent: u32) {
+    let boss = state.enemies.iter_mut().find(|e| e.id == id).unwrap();
+    boss.health = boss.max_health * percent / 100;
+}
+
+#[test]
+fn enemies_move_along_path() {
+    let (mut state, _) = boss_fight();
+    tick::run(&mut state);
+    let speed = EnemyKind::Warlord.definition().speed;
+    assert_eq!(state.enemies[0].distance, 5 * PATH_UNITS_PER_TILE + speed);
+    assert_eq!(state.enemies[0].age, 1);
+}
+
+#[test]
+fn escaped_enemies_cost_lives() {
+    let mut state = new_game::create(Default::default());
+    let end = state.map.path.len() as u32 * PATH_UNITS_PER_TILE - 1;
+    enemies::spawn(&mut state, EnemyKind::Grunt, end);
+    enemies::spawn(&mut state, EnemyKind::Grunt, 0);
+    enemies::advance(&mut state);
+    assert_eq!(state.enemies.len(), 1);
+    assert_eq!(state.lives, STARTING_LIVES - 1);
+}
+
+#[test]
+fn flying_enemies_fly_straight_across_map() {
+    let state = new_game::create(Default::default());
+    let map = &state.map;
+    let (start, end) = (map.path[0], *map.path.last().unwrap());
+    let steps = start.x.abs_diff(end.x).max(start.y.abs_diff(end.y)) as u32;
+    assert!(steps < map.path.len() as u32, "shorter than the path");
+
+    assert_eq!(map.flight_position(0), Some(start));
+    assert_eq!(map.flight_position(steps * PATH_UNITS_PER_TILE), Some(end));
+    assert_eq!(map.flight_position((steps + 1) * PATH_UNITS_PER_TILE), None);
+    for step in 1..=steps {
+        let a = map.flight_position((step - 1) * PATH_UNITS_PER_TILE).unwrap();
+        let b = map.flight_position(step * PATH_UNITS_PER_TILE).unwrap();
+        assert!(a.x.abs_diff(b.x) <= 1 && a.y.abs_diff(b.y) <= 1, "{a:?} to {b:?}");
+    }
+}
+
+#[test]
+fn flying_enemies_escape_at_end_of_flight() {
+    let mut state = new_game::create(Default::default());
+    let map = &state.map;
+    let (start, end) = (map.path[0], *map.path.last().unwrap());
+    let steps = start.x.abs_diff(end.x).max(start.y.abs_diff(end.y)) as u32;
+    let distance = (steps + 1) * PATH_UNITS_PER_TILE - 1;
+    enemies::spawn(&mut state, EnemyKind::Bat, distance);
+    enemies::spawn(&mut state, EnemyKind::Grunt, distance);
+    enemies::advance(&mut state);
+    assert_eq!(state.enemies.len(), 1);
+    assert_eq!(state.enemies[0].kind, EnemyKind::Grunt);
+    assert_eq!(state.lives, STARTING_LIVES - 1);
+}
+
+#[test]
+fn towers_attack_flying_enemies_away_from_path() {
+    let mut state = new_game::create(Default::default());
+    state.next_wave_tick = None;
+    let distance = 45 * PATH_UNITS_PER_TILE;
+    let bat = state.map.flight_position(distance).unwrap();
+    let position = TilePosition::new(bat.x - 1, bat.y);
+    let range = TowerKind::Cannon.definition().stats.range;
+    assert!(!state.map.path.iter().any(|&p| towers::within_range(position, p, range)));
+
+    towers::build(&mut state, TowerKind::Cannon, position);
+    enemies::spawn(&mut state, EnemyKind::Bat, distance);
+    towers::attack(&mut state);
+    assert!(state.enemies.is_empty());
+}
+
+#[test]
+fn spawn_assigns_unique_ids() {
+    let mut state = new_game::create(Default::default());
+    let first = enemies::spawn(&mut state, EnemyKind::Grunt, 0);
+    let second = enemies::spawn(&mut state, EnemyKind::Grunt, 0);
+    assert_ne!(first, second);
+}
+
+#[test]
+fn spawn_minions_on_timer() {
+    let (mut state, _) = boss_fight();
+    for _ in 0..199 {
+        tick::run(&mut state);
+    }
+    assert_eq!(state.enemies.len(), 1);
+    tick::run(&mut state);
+    assert_eq!(state.enemies.len(), 4);
+    let boss = &state.enemies[0];
+    assert!(state.enemies[1..].iter().all(|e| e.kind == EnemyKind::Grunt));
+    assert!(state.enemies[1..].iter().all(|e| e.distance == boss.distance));
+}
+
+#[test]
+fn full_health_boss_has_no_phase_abilities() {
+    let (mut state, _) = boss_fight();
+    abilities::run(&mut state);
+    assert_eq!(state.enemies[0].phase, 0);
+    assert_eq!(state.enemies.len(), 1);
+}
+
+#[test]
+fn disable_nearby_towers() {
+    let (mut state, id) = boss_fight();
+    let boss_tile = state.map.path[5];
+    let near = Tower::new(TowerKind::Arrow, boss_tile);
+    let far = Tower::new(TowerKind::Arrow, state.map.path[40]);
+    state.towers = vec![near, far];
+    state.tick = 50;
+    set_health_percent(&mut state, id, 70);
+    abilities::run(&mut state);
+    assert_eq!(state.enemies[0].phase, 1);
+    assert!(state.towers[0].is_disabled(149));
+    assert!(!state.towers[0].is_disabled(150));
+    assert!(!state.towers[1].is_disabled(50));
+}
+
+#[test]
+fn heal_nearby_allies() {
+    let (mut state, id) = boss_fight();
+    let near = enemies::spawn(&mut state, EnemyKind::Brute, 7 * PATH_UNITS_PER_TILE);
+    let far = enemies::spawn(&mut state, EnemyKind::Brute, 20 * PATH_UNITS_PER_TILE);
+    for enemy in &mut state.enemies[1..] {
+        enemy.health = 10;
+    }
+    set_health_percent(&mut state, id, 75);
+    abilities::run(&mut state);
+    set_health_percent(&mut state, id, 50);
+    abilities::run(&mut state);
+    let health = |id: EnemyId| state.enemies.iter().find(|e| e.id == id).unwrap().health;
+    assert_eq!(health(near), 30);
+    assert_eq!(health(far), 10);
+    assert_eq!(health(id), 200, "does not heal itself");
+}
+
+#[test]
+fn heal_flying_allies_by_position() {
+    let mut state = new_game::create(Default::default());
+    state.next_wave_tick = None;
+    // The Warlord is partway down the first descent of the path, which the bats
+    // fly past at different points
+    let id = enemies::spawn(&mut state, EnemyKind::Warlord, 20 * PATH_UNITS_PER_TILE);
+    let near = enemies::spawn(&mut state, EnemyKind::Bat, 14 * PATH_UNITS_PER_TILE);
+    let far = enemies::spawn(&mut state, EnemyKind::Bat, 20 * PATH_UNITS_PER_TILE);
+    for enemy in &mut state.enemies[1..] {
+        enemy.health = 1;
+    }
+    set_health_percent(&mut state, id, 75);
+    abilities::run(&mut state);
+    set_health_percent(&mut state, id, 50);
+    abilities::run(&mut state);
+    let health = |id: EnemyId| state.enemies.iter().find(|e| e.id == id).unwrap().health;
+    assert!(health(near) > 1, "near on the map, though behind on the path");
+    assert_eq!(health(far), 1, "level on the path, but far away on the map");
+}
+
+#[test]
+fn phases_trigger_once_in_order() {
+    let (mut state, id) = boss_fight();
+    let speed = state.enemies[0].speed;
+    set_health_percent(&mut state, id, 10);
+    abilities::run(&mut state);
+    assert_eq!(state.enemies[0].phase, 3);
+    assert_eq!(state.enemies[0].speed, speed + 3);
+    abilities::run(&mut state);
+    assert_eq!(state.enemies[0].phase, 3);
+    assert_eq!(state.enemies[0].speed, speed + 3);
+}
+
+#[test]
+fn enemy_definitions_valid() {
+    assert_eq!(enemy::validate_definitions(), Ok(()));
+}
+
+const fn ability(trigger: AbilityTrigger) -> Ability {
+    Ability { trigger, effect: AbilityEffect::SpeedUp(1) }
+}
+
+#[test]
+fn invalid_abilities() {
+    let zero = [ability(AbilityTrigger::Every(0))];
+    assert_eq!(enemy::validate_abilities(&zero), Err(AbilityError::ZeroInterval(0)));
+    let above = [ability(AbilityTrigger::HealthBelow(101))];
+    assert_eq!(enemy::validate_abilities(&above), Err(AbilityError::ThresholdAbove100(0)));
+    let ascending = [
+        ability(AbilityTrigger::HealthBelow(25)),
+        ability(AbilityTrigger::Every(10)),
+        ability(AbilityTrigger::HealthBelow(50)),
+    ];
+    assert_eq!(enemy::validate_abilities(&ascending), Err(AbilityError::ThresholdNotDescending(2)));
+}
+
+#[test]
+fn disabled_tower_holds_fire() {
+    let (mut state, id) = boss_fight();
+    state.towers = vec![Tower::new(TowerKind::Arrow, state.map.path[5])];
+    state.towers[0].disabled_until = 10;
+    towers::attack(&mut state);
+    assert_eq!(state.enemies[0].health, state.enemies[0].max_health);
+    state.tick = 10;
+    towers::attack(&mut state);
+    let damage = TowerKind::Arrow.definition().stats.damage;
+    assert_eq!(state.enemies[0].id, id);
+    assert_eq!(state.enemies[0].health, state.enemies[0].max_health - damage);
+}
+
+#[test]
+fn heal_does_not_overflow() {
+    let (mut state, id) = boss_fight();
+    let ally = enemies::spawn(&mut state, EnemyKind::Brute, 6 * PATH_UNITS_PER_TILE);
+    state.enemies[1].health = u32::MAX;
+    state.enemies[1].max_health = u32::MAX;
+    set_health_percent(&mut state, id, 50);
+    abilities::run(&mut state);
+    assert_eq!(state.enemies.iter().find(|e| e.id == ally).unwrap().health, u32::MAX);
+}
@@ -0,0 +1,80 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{Duration, Instant};
+
+use display::core::animation::{Easing, Lerp, Sprite, Tween};
+use ratatui::layout::Position;
+use ratatui::style::Color;
+
+#[test]
+fn easing_endpoints() {
+    for easing in [Easing::Linear, Easing::EaseIn, Easing::EaseOut, Easing::EaseInOut] {
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(1.0), 1.0);
+        assert_eq!(easing.apply(2.0), 1.0);
+    }
+}
+
+#[test]
+fn easing_curves() {
+    assert_eq!(Easing::Linear.apply(0.25), 0.25);
+    assert_eq!(Easing::EaseIn.apply(0.5), 0.25);
+    assert_eq!(Easing::EaseOut.apply(0.5), 0.75);
+    assert_eq!(Easing::EaseInOut.apply(0.25), 0.125);
+    assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+}
+
+#[test]
+fn tween_value() {
+    let start = Instant::now();
+    let tween = Tween::new(10.0, 20.0, start, Duration::from_secs(1));
+    assert_eq!(tween.value(start), 10.0);
+    assert_eq!(tween.value(start + Duration::from_millis(250)), 12.5);
+    assert_eq!(tween.value(start + Duration::from_secs(5)), 20.0);
+    assert!(!tween.is_finished(start + Duration::from_millis(999)));
+    assert!(tween.is_finished(start + Duration::from_secs(1)));
+}
+
+#[test]
+fn tween_with_easing() {
+    let start = Instant::now();
+    let tween = Tween::new(0.0, 100.0, start, Duration::from_secs(1)).easing(Easing::EaseIn);
+    assert_eq!(tween.value(start + Duration::from_millis(500)), 25.0);
+}
+
+#[test]
+fn tween_position() {
+    let start = Instant::now();
+    let tween =
+        Tween::new(Position::new(0, 10), Position::new(8, 0), start, Duration::from_secs(2));
+    assert_eq!(tween.value(start + Duration::from_millis(500)), Position::new(2, 8));
+}
+
+#[test]
+fn lerp_colors() {
+    assert_eq!(Color::Rgb(0, 100, 200).lerp(Color::Rgb(100, 0, 200), 0.5), Color::Rgb(50, 50, 200));
+    assert_eq!(Color::Indexed(1).lerp(Color::Indexed(2), 0.4), Color::Indexed(1));
+    assert_eq!(Color::Indexed(1).lerp(Color::Indexed(2), 0.6), Color::Indexed(2));
+}
+
+#[test]
+fn sprite_frames() {
+    let sprite = Sprite { frames: &["a", "b", "c"], frame_length: 2.0 };
+    assert_eq!(sprite.frame(0.0), "a");
+    assert_eq!(sprite.frame(1.9), "a");
+    assert_eq!(sprite.frame(2.0), "b");
+    assert_eq!(sprite.frame(5.0), "c");
+    assert_eq!(sprite.frame(6.0), "a");
+}
@@ -0,0 +1,336 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::{GameAction, InterfaceAction};
+use data::core::game_state::GameSpeed;
+use data::core::interface_state::{Confirmation, InterfaceState, Modal, Screen};
+use data::core::map::TilePosition;
+use data::core::settings::Settings;
+use data::core::tower::TowerKind;
+use display::core::layout::{GameLayout, ScreenLayout, ScreenOptions};
+use display::game::visual_effects::VisualEffects;
+use display::panels::event_log::EventHistory;
+use ratatui::layout::Rect;
+use rules::core::{new_game, towers};
+use termdef::app::App;
+use testing::events;
+use testing::harness::TestHarness;
+
+#[test]
+fn main_menu() {
+    let data = new_game::create(Default::default());
+    let interface = InterfaceState::default();
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(80, 24);
+    harness.render(|| App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn terminal_too_small() {
+    let data = new_game::create(Default::default());
+    let interface = InterfaceState::default();
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(60, 10);
+    let actions = harness.key(KeyCode::Char('q'), || App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    assert_eq!(actions, vec![InterfaceAction::Exit]);
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn terminal_too_small_in_game() {
+    let data = new_game::create(Default::default());
+    let interface = InterfaceState { screen: Screen::Game, ..Default::default() };
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(60, 10);
+    let actions = harness.key(KeyCode::Char('q'), || App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    assert_eq!(actions, vec![InterfaceAction::ShowModal(Modal::Confirm(Confirmation::QuitGame))]);
+    assert!(harness.displays("The game is paused."));
+}
+
+#[test]
+fn pause_menu() {
+    let data = new_game::create(Default::default());
+    let interface = InterfaceState {
+        screen: Screen::Game,
+        modals: vec![Modal::PauseMenu],
+        ..Default::default()
+    };
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(82, 26);
+    harness.render(|| App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn settings_menu() {
+    let interface = InterfaceState { modals: vec![Modal::Settings], ..Default::default() };
+    let data = new_game::create(Default::default());
+    let settings = Settings { scale_map: true, ..Default::default() };
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(82, 26);
+    harness.render(|| App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn escape_opens_pause_menu() {
+    let data = new_game::create(Default::default());
+    let interface = InterfaceState { screen: Screen::Game, ..Default::default() };
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(80, 24);
+    let actions = harness.key(KeyCode::Esc, || App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    assert_eq!(actions, vec![InterfaceAction::ShowModal(Modal::PauseMenu)]);
+}
+
+#[test]
+fn quit_asks_for_confirmation() {
+    let data = new_game::create(Default::default());
+    let interface = InterfaceState { screen: Screen::Game, ..Default::default() };
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(80, 24);
+    let actions = harness.key(KeyCode::Char('q'), || App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    assert_eq!(actions, vec![InterfaceAction::ShowModal(Modal::Confirm(Confirmation::QuitGame))]);
+}
+
+#[test]
+fn actions_produced_in_input_order() {
+    let data = new_game::create(Default::default());
+    let interface = InterfaceState { screen: Screen::Game, ..Default::default() };
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(80, 24);
+    harness.send(events::key_press(KeyCode::Char('f')));
+    harness.send(events::key_press(KeyCode::Esc));
+    let actions = harness.render(|| App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    assert_eq!(actions, vec![
+        GameAction::SetSpeed(GameSpeed::Fast).into(),
+        InterfaceAction::ShowModal(Modal::PauseMenu)
+    ]);
+}
+
+#[test]
+fn repeated_key_produces_action_per_press() {
+    let data = new_game::create(Default::default());
+    let interface = InterfaceState { screen: Screen::Game, ..Default::default() };
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(80, 24);
+    for _ in 0..3 {
+        harness.send(events::key_press(KeyCode::Char('f')));
+    }
+    let actions = harness.render(|| App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    assert_eq!(actions, vec![GameAction::SetSpeed(GameSpeed::Fast).into(); 3]);
+}
+
+#[test]
+fn toggle_debug_layout() {
+    let data = new_game::create(Default::default());
+    let interface = InterfaceState::default();
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(80, 24);
+    let actions = harness.key(KeyCode::F(12), || App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    assert_eq!(actions, vec![InterfaceAction::ToggleDebugLayout]);
+}
+
+#[test]
+fn debug_layout() {
+    let data = new_game::create(Default::default());
+    let interface = InterfaceState { debug_layout: true, ..Default::default() };
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(80, 24);
+    harness.render(|| App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn side_panels() {
+    let data = new_game::create(Default::default());
+    let interface = InterfaceState { screen: Screen::Game, ..Default::default() };
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(142, 26);
+    harness.render(|| App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn side_panels_show_selected_tower_and_events() {
+    let mut data = new_game::create(Default::default());
+    let position = TilePosition::new(4, 4);
+    towers::build(&mut data, TowerKind::Arrow, position);
+    let interface = InterfaceState {
+        screen: Screen::Game,
+        selected_tower: Some(position),
+        ..Default::default()
+    };
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let mut history = EventHistory::default();
+    history.observe(&data);
+    let mut harness = TestHarness::new(142, 26);
+    harness.render(|| App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    assert!(harness.displays("Arrow built"));
+    assert!(harness.displays("Sells for 37g"));
+}
+
+#[test]
+fn side_panel_shows_hovered_tower() {
+    let mut data = new_game::create(Default::default());
+    let position = TilePosition::new(4, 4);
+    towers::build(&mut data, TowerKind::Arrow, position);
+    let interface = InterfaceState { screen: Screen::Game, ..Default::default() };
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(142, 26);
+    harness.render(|| App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    assert!(harness.displays("Select a tower"));
+
+    let options = ScreenOptions { side_panels: true, scale_map: false };
+    let screen = ScreenLayout::new(Rect::new(0, 0, 142, 26), options).unwrap();
+    let map = GameLayout::new(screen.game).battlefield;
+    harness.hover(map.x + position.x, map.y + position.y, || App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    assert!(harness.displays("Sells for 37g"));
+}
+
+#[test]
+fn no_side_panels_on_main_menu() {
+    let data = new_game::create(Default::default());
+    let interface = InterfaceState::default();
+    let settings = Settings::default();
+    let effects = VisualEffects::default();
+    let history = EventHistory::default();
+    let mut harness = TestHarness::new(142, 26);
+    harness.render(|| App {
+        data: &data,
+        interface: &interface,
+        settings: &settings,
+        effects: &effects,
+        history: &history,
+    });
+    assert!(harness.displays("New Game"));
+    assert!(!harness.displays("Events"));
+}
@@ -0,0 +1,311 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::Cell;
+
+use data::core::colors;
+use data::core::enemy::{Enemy, EnemyId, EnemyKind};
+use data::core::game_event::GameEvent;
+use data::core::game_state::GameState;
+use data::core::interface_state::Tool;
+use data::core::map::{TilePosition, MAP_HEIGHT, MAP_WIDTH, PATH_UNITS_PER_TILE};
+use data::core::projectile::{Effect, EffectKind, Projectile};
+use data::core::tower::{Tower, TowerKind};
+use display::core::render_context::RenderContext;
+use display::core::scale::Scale;
+use display::game::battlefield::Battlefield;
+use display::game::visual_effects::{VisualEffects, FLOAT_DURATION};
+use ratatui::prelude::*;
+use rules::core::new_game;
+use testing::harness::TestHarness;
+use testing::screen;
+
+fn battle() -> GameState {
+    let mut state = new_game::create(Default::default());
+    let start = state.map.path[0];
+    state.towers = vec![
+        Tower::new(TowerKind::Arrow, TilePosition::new(4, 4)),
+        Tower { upgrades: vec![0], ..Tower::new(TowerKind::Cannon, TilePosition::new(10, 8)) },
+        Tower { upgrades: vec![0, 1], ..Tower::new(TowerKind::Frost, TilePosition::new(20, 14)) },
+    ];
+    state.enemies =
+        vec![Enemy::new(EnemyId(1), EnemyKind::Grunt, 3 * PATH_UNITS_PER_TILE), Enemy {
+            health: 5,
+            ..Enemy::new(EnemyId(2), EnemyKind::Brute, 6 * PATH_UNITS_PER_TILE)
+        }];
+    state.projectiles =
+        vec![Projectile { source: TowerKind::Arrow, position: TilePosition::new(5, 5) }];
+    state.effects = vec![Effect {
+        kind: EffectKind::Explosion,
+        position: TilePosition::new(start.x + 1, start.y),
+        remaining_ticks: 3,
+    }];
+    state
+}
+
+#[test]
+fn battlefield() {
+    let state = battle();
+    let effects = VisualEffects::default();
+    let mut harness = TestHarness::new(MAP_WIDTH, MAP_HEIGHT);
+    harness.render(|| Battlefield {
+        state: &state,
+        effects: &effects,
+        scale: Scale::NORMAL,
+        range_circles: false,
+        health_bars: false,
+        damage_numbers: false,
+        tool: Tool::Select,
+        selected_tower: None,
+    });
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn scaled_tower_drawn_in_tile_center() {
+    let state = battle();
+    let effects = VisualEffects::default();
+    let mut harness = TestHarness::new(MAP_WIDTH * 4, MAP_HEIGHT * 2);
+    harness.render(|| Battlefield {
+        state: &state,
+        effects: &effects,
+        scale: Scale::LARGE,
+        range_circles: false,
+        health_bars: false,
+        damage_numbers: false,
+        tool: Tool::Select,
+        selected_tower: None,
+    });
+    assert_eq!(screen::find(harness.buffer(), "A"), Some((4 * 4 + 1, 4 * 2)));
+}
+
+/// Renders a battlefield and records the tile it reports as hovered.
+struct HoverProbe<'a> {
+    battlefield: Battlefield<'a>,
+    hovered: &'a Cell<Option<TilePosition>>,
+}
+
+impl<'a> StatefulWidget for HoverProbe<'a> {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        self.hovered.set(self.battlefield.hovered_tile(area, context));
+        self.battlefield.render(area, buf, context);
+    }
+}
+
+#[test]
+fn hovered_tile() {
+    let state = battle();
+    let effects = VisualEffects::default();
+    let hovered = Cell::new(None);
+    let mut harness = TestHarness::new(MAP_WIDTH * 2, MAP_HEIGHT);
+    let probe = || HoverProbe {
+        battlefield: Battlefield { scale: Scale::WIDE, ..plain(&state, &effects) },
+        hovered: &hovered,
+    };
+    harness.hover(7, 3, probe);
+    assert_eq!(hovered.get(), Some(TilePosition::new(3, 3)));
+}
+
+#[test]
+fn hovered_tile_cleared_outside_map() {
+    let state = battle();
+    let effects = VisualEffects::default();
+    let hovered = Cell::new(None);
+    let mut harness = TestHarness::new(MAP_WIDTH + 5, MAP_HEIGHT);
+    let probe = || HoverProbe { battlefield: plain(&state, &effects), hovered: &hovered };
+    harness.hover(3, 3, probe);
+    harness.hover(MAP_WIDTH + 2, 3, probe);
+    assert_eq!(hovered.get(), None);
+}
+
+fn overlays<'a>(state: &'a GameState, effects: &'a VisualEffects) -> Battlefield<'a> {
+    Battlefield {
+        state,
+        effects,
+        scale: Scale::NORMAL,
+        range_circles: false,
+        health_bars: true,
+        damage_numbers: true,
+        tool: Tool::Select,
+        selected_tower: None,
+    }
+}
+
+#[test]
+fn health_bars() {
+    let state = battle();
+    let effects = VisualEffects::default();
+    let mut harness = TestHarness::new(MAP_WIDTH, MAP_HEIGHT);
+    harness.render(|| overlays(&state, &effects));
+    // The brute at a tenth of its health shows a sliver of a bar above it
+    let brute = state.map.path[6];
+    assert_eq!(harness.buffer().get(brute.x - 1, brute.y - 1).symbol(), "▎");
+    assert_eq!(harness.buffer().get(brute.x, brute.y - 1).symbol(), " ");
+}
+
+#[test]
+fn floating_damage_number_rises() {
+    let mut state = battle();
+    let mut effects = VisualEffects::default();
+    let mut harness = TestHarness::new(MAP_WIDTH, MAP_HEIGHT);
+    effects.observe(&state, harness.now());
+    harness.render(|| overlays(&state, &effects));
+    state.tick += 1;
+    state.enemies[0].health = 4;
+    let grunt = state.map.path[3];
+    state.events.push(GameEvent::EnemyDamaged { position: grunt, amount: 6 });
+    effects.observe(&state, harness.now());
+    harness.render(|| overlays(&state, &effects));
+    assert_eq!(screen::find(harness.buffer(), "-6"), Some((grunt.x - 1, grunt.y)));
+    assert!(harness.context().redraw_at().is_some());
+
+    harness.advance(FLOAT_DURATION / 4);
+    effects.observe(&state, harness.now());
+    harness.render(|| overlays(&state, &effects));
+    assert_eq!(screen::find(harness.buffer(), "-6"), Some((grunt.x - 1, grunt.y - 1)));
+
+    harness.advance(FLOAT_DURATION / 4);
+    effects.observe(&state, harness.now());
+    harness.render(|| overlays(&state, &effects));
+    assert_eq!(screen::find(harness.buffer(), "-6"), Some((grunt.x - 1, grunt.y - 2)));
+
+    harness.advance(FLOAT_DURATION / 2);
+    effects.observe(&state, harness.now());
+    harness.render(|| overlays(&state, &effects));
+    assert!(!harness.displays("-6"));
+}
+
+#[test]
+fn overlays_disabled() {
+    let mut state = battle();
+    let mut effects = VisualEffects::default();
+    let mut harness = TestHarness::new(MAP_WIDTH, MAP_HEIGHT);
+    effects.observe(&state, harness.now());
+    harness.render(|| plain(&state, &effects));
+    state.tick += 1;
+    state.enemies[0].health = 4;
+    state.events.push(GameEvent::EnemyDamaged { position: state.map.path[3], amount: 6 });
+    effects.observe(&state, harness.now());
+    harness.render(|| plain(&state, &effects));
+    assert!(!harness.displays("-6"));
+    assert!(!harness.displays("▎"));
+}
+
+fn plain<'a>(state: &'a GameState, effects: &'a VisualEffects) -> Battlefield<'a> {
+    Battlefield {
+        state,
+        effects,
+        scale: Scale::NORMAL,
+        range_circles: false,
+        health_bars: false,
+        damage_numbers: false,
+        tool: Tool::Select,
+        selected_tower: None,
+    }
+}
+
+fn large<'a>(state: &'a GameState, effects: &'a VisualEffects) -> Battlefield<'a> {
+    Battlefield {
+        state,
+        effects,
+        scale: Scale::LARGE,
+        range_circles: false,
+        health_bars: false,
+        damage_numbers: false,
+        tool: Tool::Select,
+        selected_tower: None,
+    }
+}
+
+#[test]
+fn enemy_motion_interpolated_between_ticks() {
+    let mut state = battle();
+    let mut effects = VisualEffects::default();
+    let mut harness = TestHarness::new(MAP_WIDTH * 4, MAP_HEIGHT * 2);
+    effects.observe(&state, harness.now());
+    harness.render(|| large(&state, &effects));
+    let row = state.map.path[3].y * 2;
+    assert_eq!(screen::find(harness.buffer(), "g"), Some((3 * 4 + 1, row)));
+
+    state.tick += 1;
+    state.enemies[0].distance += PATH_UNITS_PER_TILE;
+    harness.context().set_tick_progress(0.0);
+    effects.observe(&state, harness.now());
+    harness.render(|| large(&state, &effects));
+    assert_eq!(screen::find(harness.buffer(), "g"), Some((3 * 4 + 1, row)));
+
+    harness.context().set_tick_progress(0.5);
+    effects.observe(&state, harness.now());
+    harness.render(|| large(&state, &effects));
+    assert_eq!(screen::find(harness.buffer(), "q"), Some((3 * 4 + 3, row)));
+    assert!(harness.context().redraw_at().is_some());
+
+    harness.context().set_tick_progress(1.0);
+    effects.observe(&state, harness.now());
+    harness.render(|| large(&state, &effects));
+    assert_eq!(screen::find(harness.buffer(), "g"), Some((4 * 4 + 1, row)));
+}
+
+#[test]
+fn disabled_tower_dimmed() {
+    let mut state = battle();
+    let effects = VisualEffects::default();
+    state.tick = 10;
+    state.towers[0].disabled_until = 20;
+    let mut harness = TestHarness::new(MAP_WIDTH, MAP_HEIGHT);
+    harness.render(|| overlays(&state, &effects));
+    assert_eq!(harness.buffer().get(4, 4).fg, colors::dark_gray());
+    state.tick = 20;
+    harness.render(|| overlays(&state, &effects));
+    assert_eq!(harness.buffer().get(4, 4).fg, colors::white());
+}
+
+#[test]
+fn range_circle_outlines_selected_tower_range() {
+    let state = battle();
+    let effects = VisualEffects::default();
+    let mut harness = TestHarness::new(MAP_WIDTH, MAP_HEIGHT);
+    let selected = |range_circles| Battlefield {
+        range_circles,
+        selected_tower: Some(TilePosition::new(4, 4)),
+        ..plain(&state, &effects)
+    };
+    harness.render(|| selected(true));
+    // The arrow tower has a range of 4 tiles
+    assert_eq!(harness.buffer().get(8, 4).bg, colors::blue_gray());
+    assert_eq!(harness.buffer().get(4, 0).bg, colors::blue_gray());
+    assert_ne!(harness.buffer().get(6, 4).bg, colors::blue_gray());
+    assert_ne!(harness.buffer().get(9, 4).bg, colors::blue_gray());
+
+    harness.render(|| selected(false));
+    assert_ne!(harness.buffer().get(8, 4).bg, colors::blue_gray());
+}
+
+#[test]
+fn range_circle_previews_tower_being_built() {
+    let state = battle();
+    let effects = VisualEffects::default();
+    let mut harness = TestHarness::new(MAP_WIDTH, MAP_HEIGHT);
+    let building = || Battlefield {
+        range_circles: true,
+        tool: Tool::Build(TowerKind::Cannon),
+        ..plain(&state, &effects)
+    };
+    harness.hover(30, 10, building);
+    // The cannon has a range of 3 tiles
+    assert_eq!(harness.buffer().get(33, 10).bg, colors::blue_gray());
+    assert_ne!(harness.buffer().get(34, 10).bg, colors::blue_gray());
+}
@@ -0,0 +1,234 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::InterfaceAction;
+use data::core::colors;
+use data::core::widget_id::{WidgetId, WidgetName};
+use display::core::button::{Button, ButtonSize};
+use display::core::tooltip::TOOLTIP_DELAY;
+use ratatui::layout::Rect;
+use ratatui::style::Stylize;
+use testing::harness::TestHarness;
+use testing::{events, screen};
+
+fn button() -> Button {
+    Button::new()
+        .label("Start")
+        .action(InterfaceAction::NewGame)
+        .id(WidgetId::new(WidgetName::NewGame))
+        .build()
+}
+
+fn shop_button() -> Button {
+    Button::new()
+        .label("Arrow Tower")
+        .action(InterfaceAction::NewGame)
+        .id(WidgetId::new(WidgetName::NewGame))
+        .hotkey(KeyCode::Char('1'))
+        .secondary("50g")
+        .build()
+}
+
+fn disabled_button() -> Button {
+    Button::new()
+        .label("Arrow Tower")
+        .action(InterfaceAction::NewGame)
+        .id(WidgetId::new(WidgetName::NewGame))
+        .hotkey(KeyCode::Char('1'))
+        .disabled("Not enough gold")
+        .build()
+}
+
+#[test]
+fn default_style() {
+    let mut harness = TestHarness::new(12, 3);
+    harness.render(button);
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn hover() {
+    let mut harness = TestHarness::new(12, 3);
+    harness.hover(5, 1, button);
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn hover_then_leave() {
+    let mut harness = TestHarness::new(20, 5).with_widget_area(Rect::new(0, 0, 12, 3));
+    harness.hover(5, 1, button);
+    harness.hover(15, 4, button);
+
+    let mut expected = TestHarness::new(20, 5).with_widget_area(Rect::new(0, 0, 12, 3));
+    expected.render(button);
+    assert_eq!(harness.snapshot(), expected.snapshot());
+}
+
+#[test]
+fn pressed() {
+    let mut harness = TestHarness::new(12, 3);
+    harness.send(events::mouse_down(5, 1));
+    harness.render(button);
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn click() {
+    let mut harness = TestHarness::new(12, 3);
+    assert_eq!(harness.click(5, 1, button), vec![InterfaceAction::NewGame]);
+}
+
+#[test]
+fn click_outside() {
+    let mut harness = TestHarness::new(20, 5).with_widget_area(Rect::new(0, 0, 12, 3));
+    assert!(harness.click(15, 4, button).is_empty());
+}
+
+#[test]
+fn release_outside() {
+    let mut harness = TestHarness::new(20, 5).with_widget_area(Rect::new(0, 0, 12, 3));
+    harness.send(events::mouse_down(5, 1));
+    harness.render(button);
+    harness.send(events::mouse_up(15, 4));
+    assert!(harness.render(button).is_empty());
+}
+
+#[test]
+fn click_within_single_frame() {
+    let mut harness = TestHarness::new(12, 3);
+    harness.send(events::mouse_down(5, 1));
+    harness.send(events::mouse_up(5, 1));
+    assert_eq!(harness.render(button), vec![InterfaceAction::NewGame]);
+}
+
+#[test]
+fn multiple_clicks_within_single_frame() {
+    let mut harness = TestHarness::new(12, 3);
+    for _ in 0..3 {
+        harness.send(events::mouse_down(5, 1));
+        harness.send(events::mouse_up(5, 1));
+    }
+    assert_eq!(harness.render(button), vec![InterfaceAction::NewGame; 3]);
+}
+
+#[test]
+fn hover_uses_last_mouse_position_in_frame() {
+    let mut harness = TestHarness::new(20, 5).with_widget_area(Rect::new(0, 0, 12, 3));
+    harness.send(events::mouse_moved(15, 4));
+    harness.send(events::mouse_moved(5, 1));
+    harness.render(button);
+    let mut expected = TestHarness::new(20, 5).with_widget_area(Rect::new(0, 0, 12, 3));
+    expected.hover(5, 1, button);
+    assert_eq!(harness.snapshot(), expected.snapshot());
+}
+
+#[test]
+fn hotkey_and_secondary_line() {
+    let mut harness = TestHarness::new(19, 4);
+    harness.render(shop_button);
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn icon_before_label() {
+    let icon = |disabled: bool| {
+        move || {
+            let button = Button::new()
+                .label("Arrow Tower")
+                .action(InterfaceAction::NewGame)
+                .id(WidgetId::new(WidgetName::NewGame))
+                .hotkey(KeyCode::Char('1'))
+                .icon("A".fg(colors::cyan()))
+                .size(ButtonSize::Small);
+            if disabled {
+                button.disabled("Not enough gold").build()
+            } else {
+                button.build()
+            }
+        }
+    };
+    let mut harness = TestHarness::new(19, 1);
+    harness.render(icon(false));
+    assert_eq!(screen::find(harness.buffer(), "[1] A Arrow Tower"), Some((1, 0)));
+    assert_eq!(harness.buffer().get(5, 0).fg, colors::cyan());
+
+    harness.render(icon(true));
+    assert_eq!(harness.buffer().get(5, 0).fg, colors::dark_gray());
+}
+
+#[test]
+fn hotkey_press() {
+    let mut harness = TestHarness::new(19, 4);
+    assert_eq!(harness.key(KeyCode::Char('1'), shop_button), vec![InterfaceAction::NewGame]);
+}
+
+#[test]
+fn selected() {
+    let mut harness = TestHarness::new(12, 3);
+    harness.render(|| {
+        Button::new()
+            .label("Start")
+            .action(InterfaceAction::NewGame)
+            .id(WidgetId::new(WidgetName::NewGame))
+            .selected(true)
+            .build()
+    });
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn small_size() {
+    let mut harness = TestHarness::new(20, 1);
+    harness.render(|| {
+        Button::new()
+            .label("Arrow")
+            .action(InterfaceAction::NewGame)
+            .id(WidgetId::new(WidgetName::NewGame))
+            .secondary("50g")
+            .size(ButtonSize::Small)
+            .build()
+    });
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn medium_size() {
+    let mut harness = TestHarness::new(12, 3);
+    harness.render(|| {
+        Button::new()
+            .label("Start")
+            .action(InterfaceAction::NewGame)
+            .id(WidgetId::new(WidgetName::NewGame))
+            .size(ButtonSize::Medium)
+            .build()
+    });
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn disabled_ignores_input() {
+    let mut harness = TestHarness::new(19, 4);
+    assert!(harness.click(5, 1, disabled_button).is_empty());
+    assert!(harness.key(KeyCode::Char('1'), disabled_button).is_empty());
+}
+
+#[test]
+fn disabled_hover_shows_reason() {
+    let mut harness = TestHarness::new(24, 8).with_widget_area(Rect::new(0, 0, 19, 3));
+    harness.hover(5, 1, disabled_button);
+    harness.advance(TOOLTIP_DELAY);
+    harness.render(disabled_button);
+    insta::assert_snapshot!(harness.snapshot());
+}
@@ -0,0 +1,272 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use crossterm::event::KeyCode;
+use data::core::actions::InterfaceAction;
+use data::core::game_state::{GameSpeed, TICK_DURATION};
+use data::core::interface_state::{Modal, Screen, Tool};
+use data::core::map::TilePosition;
+use data::core::settings::{AutosaveFrequency, KeyBinding, Theme};
+use data::core::tower::TowerKind;
+use ratatui::style::Color;
+use rules::core::new_game::STARTING_GOLD;
+use termdef::app;
+use testing::driver::TestDriver;
+
+#[test]
+fn start_game() {
+    let mut driver = TestDriver::default();
+    assert!(driver.displays("New Game"));
+    driver.click("New Game");
+    assert_eq!(driver.state.interface.screen, Screen::Game);
+    assert_eq!(driver.state.data.tick, 0);
+}
+
+#[test]
+fn simulation_ticks() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").wait_ticks(300);
+    assert_eq!(driver.state.data.tick, 300);
+}
+
+#[test]
+fn change_speed() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").key(KeyCode::Char('f')).wait_ticks(1);
+    let start = driver.state.data.tick;
+    driver.wait_ticks(100);
+    assert_eq!(driver.state.data.tick - start, 200);
+}
+
+#[test]
+fn catch_up_ticks_limited() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").stall(TICK_DURATION * 1000).wait(Duration::ZERO);
+    assert_eq!(driver.state.data.tick, app::MAX_CATCH_UP_TICKS as u64);
+    driver.wait_ticks(10);
+    assert_eq!(driver.state.data.tick, app::MAX_CATCH_UP_TICKS as u64 + 10);
+}
+
+#[test]
+fn pause_freezes_simulation() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").wait_ticks(10).key(KeyCode::Esc);
+    assert_eq!(driver.state.interface.current_modal(), Some(Modal::PauseMenu));
+
+    driver.wait_ticks(100);
+    assert_eq!(driver.state.data.tick, 10);
+
+    driver.click("Resume").wait_ticks(10);
+    assert_eq!(driver.state.interface.current_modal(), None);
+    assert_eq!(driver.state.data.tick, 20);
+}
+
+#[test]
+fn small_terminal_freezes_simulation() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").wait_ticks(10).resize(60, 20).wait_ticks(100);
+    assert!(driver.displays("The game is paused."));
+    assert_eq!(driver.state.data.tick, 10);
+
+    driver.resize(80, 24).wait_ticks(10);
+    assert_eq!(driver.state.data.tick, 20);
+}
+
+#[test]
+fn quit_from_small_terminal_requires_confirmation() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").resize(60, 20).key(KeyCode::Char('q'));
+    assert!(!driver.state.context.should_exit());
+    assert!(driver.displays("Exit termdef?"));
+    driver.key(KeyCode::Char('y'));
+    assert!(driver.state.context.should_exit());
+}
+
+#[test]
+fn keys_handled_in_order() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").keys(&[KeyCode::Esc, KeyCode::Char('p')]);
+    assert_eq!(driver.state.interface.current_modal(), None);
+    driver.keys(&[KeyCode::Esc, KeyCode::Esc, KeyCode::Esc]);
+    assert_eq!(driver.state.interface.modals, vec![Modal::PauseMenu]);
+}
+
+#[test]
+fn restart_level() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").wait_ticks(10).key(KeyCode::Char('p')).click("Restart Level");
+    assert_eq!(driver.state.interface.current_modal(), None);
+    assert_eq!(driver.state.data.tick, 0);
+}
+
+#[test]
+fn quit_to_menu_requires_confirmation() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").key(KeyCode::Esc).click("Quit to Menu");
+    assert!(driver.displays("Return to the main menu?"));
+
+    driver.click("No");
+    assert_eq!(driver.state.interface.current_modal(), Some(Modal::PauseMenu));
+
+    driver.click("Quit to Menu").click("Yes");
+    assert_eq!(driver.state.interface.screen, Screen::MainMenu);
+    assert!(driver.displays("New Game"));
+}
+
+#[test]
+fn quit_game() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").key(KeyCode::Char('q'));
+    assert!(!driver.state.context.should_exit());
+    driver.key(KeyCode::Char('y'));
+    assert!(driver.state.context.should_exit());
+}
+
+#[test]
+fn change_autosave_frequency() {
+    let mut driver = TestDriver::default();
+    driver.click("Settings").click("Autosave");
+    assert_eq!(driver.state.settings.autosave, AutosaveFrequency::EveryFiveWaves);
+    assert_eq!(driver.saved_settings(), Some(driver.state.settings));
+}
+
+#[test]
+fn rebind_key() {
+    let mut driver = TestDriver::default();
+    driver.click("Settings").click("Pause").key(KeyCode::Char('x')).key(KeyCode::Esc);
+    assert_eq!(driver.state.settings.key_bindings.pause, KeyCode::Char('x'));
+    assert_eq!(driver.saved_settings(), Some(driver.state.settings));
+
+    driver.click("New Game").key(KeyCode::Char('x'));
+    assert_eq!(driver.state.interface.current_modal(), Some(Modal::PauseMenu));
+}
+
+#[test]
+fn rebind_to_used_key_swaps_bindings() {
+    let mut driver = TestDriver::default();
+    driver.click("Settings").click("Pause").key(KeyCode::Char('f'));
+    let bindings = driver.state.settings.key_bindings;
+    assert_eq!(bindings.pause, KeyCode::Char('f'));
+    assert_eq!(bindings.change_speed, KeyCode::Char('p'));
+}
+
+#[test]
+fn cannot_rebind_to_game_hotkey() {
+    let mut driver = TestDriver::default();
+    driver.click("Settings").click("Pause").key(KeyCode::Char('1'));
+    assert_eq!(driver.state.interface.current_modal(), Some(Modal::RebindKey(KeyBinding::Pause)));
+    assert_eq!(driver.state.settings.key_bindings.pause, KeyCode::Char('p'));
+}
+
+#[test]
+fn settings_keyboard_navigation() {
+    let mut driver = TestDriver::default();
+    driver.click("Settings").keys(&[KeyCode::Down, KeyCode::Down, KeyCode::Enter]);
+    assert_eq!(driver.state.settings.theme, Theme::Grayscale);
+    driver.keys(&[KeyCode::Up, KeyCode::Char(' ')]);
+    assert_eq!(driver.state.settings.game_speed, GameSpeed::Fast);
+
+    driver.keys(&[KeyCode::Down; 20]).key(KeyCode::Enter);
+    assert_eq!(driver.state.interface.current_modal(), None);
+}
+
+#[test]
+fn game_speed_slider() {
+    let mut driver = TestDriver::default();
+    driver.click("Settings").keys(&[KeyCode::Down, KeyCode::Right, KeyCode::Right]);
+    assert_eq!(driver.state.settings.game_speed, GameSpeed::Fastest);
+    driver.keys(&[KeyCode::Down, KeyCode::Right]);
+    assert_eq!(driver.state.settings.game_speed, GameSpeed::Fastest);
+    assert_eq!(driver.state.settings.theme, Theme::Standard);
+
+    driver.click("Range Circles");
+    assert!(!driver.state.settings.show_range_circles);
+}
+
+#[test]
+fn theme_applied_to_frame() {
+    let mut driver = TestDriver::default();
+    driver.click("Settings").click("Theme");
+    assert_eq!(driver.state.settings.theme, Theme::Grayscale);
+    assert!(driver.buffer().content.iter().all(|cell| match cell.fg {
+        Color::Rgb(r, g, b) => r == g && g == b,
+        _ => true,
+    }));
+}
+
+#[test]
+fn background_action() {
+    let mut driver = TestDriver::default();
+    driver.state.action_sender().send(InterfaceAction::NewGame).unwrap();
+    driver.wait(Duration::from_millis(100));
+    assert_eq!(driver.state.interface.screen, Screen::Game);
+}
+
+#[test]
+fn build_tower_from_shop() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").key(KeyCode::Char('1'));
+    assert_eq!(driver.state.interface.tool, Tool::Build(TowerKind::Arrow));
+
+    // The map starts below the HUD row
+    driver.click_at(4, 5);
+    assert_eq!(driver.state.data.towers.len(), 1);
+    assert_eq!(driver.state.data.towers[0].position, TilePosition::new(4, 4));
+    assert_eq!(driver.state.data.gold, STARTING_GOLD - TowerKind::Arrow.definition().cost);
+
+    driver.key(KeyCode::Esc);
+    assert_eq!(driver.state.interface.tool, Tool::Select);
+    assert_eq!(driver.state.interface.current_modal(), None);
+}
+
+#[test]
+fn shop_hotkeys_ignored_under_modal() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").key(KeyCode::Esc).key(KeyCode::Char('1'));
+    assert_eq!(driver.state.interface.tool, Tool::Select);
+    assert_eq!(driver.state.interface.current_modal(), Some(Modal::PauseMenu));
+}
+
+#[test]
+fn send_wave_early_from_hud() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").key(KeyCode::Esc).key(KeyCode::Char('n'));
+    assert_eq!(driver.state.data.wave, 0);
+    assert_eq!(driver.state.interface.current_modal(), Some(Modal::PauseMenu));
+
+    driver.key(KeyCode::Esc).key(KeyCode::Char('n'));
+    assert_eq!(driver.state.data.wave, 1);
+    assert_eq!(driver.state.data.gold, STARTING_GOLD + 30);
+}
+
+#[test]
+fn inspect_tower() {
+    let mut driver = TestDriver::default();
+    driver.click("New Game").key(KeyCode::Char('1')).click_at(4, 5).key(KeyCode::Esc);
+    driver.click_at(4, 5);
+    assert_eq!(driver.state.interface.selected_tower, Some(TilePosition::new(4, 4)));
+    assert!(driver.displays("Lv 1"));
+
+    driver.key(KeyCode::Char('s'));
+    assert!(driver.displays("Sell this tower?"));
+    assert_eq!(driver.state.data.towers.len(), 1);
+
+    driver.key(KeyCode::Char('s')).key(KeyCode::Char('y'));
+    assert_eq!(driver.state.interface.current_modal(), None);
+    assert!(driver.state.data.towers.is_empty());
+    assert_eq!(driver.state.interface.selected_tower, None);
+    assert!(driver.displays("Towers"));
+}
@@ -0,0 +1,170 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::{GameAction, InterfaceAction};
+use data::core::game_state::GameSpeed;
+use data::core::map::TilePosition;
+use data::core::widget_id::{WidgetId, WidgetName};
+use display::core::checkbox::Checkbox;
+use display::core::radio_group::RadioGroup;
+use display::core::slider::Slider;
+use ratatui::layout::Rect;
+use testing::events;
+use testing::harness::TestHarness;
+
+const ID: WidgetId = WidgetId::new(WidgetName::SettingsMenu);
+
+/// Sliders select the tower in a column of the top row of the map.
+fn choose(value: u32) -> InterfaceAction {
+    InterfaceAction::SelectTower(Some(TilePosition::new(value as u16, 0)))
+}
+
+fn checkbox() -> Checkbox {
+    Checkbox::new()
+        .label("Range Circles")
+        .checked(true)
+        .action(InterfaceAction::CloseModal)
+        .id(ID)
+        .build()
+}
+
+fn radio_group() -> RadioGroup {
+    RadioGroup::new()
+        .id(ID)
+        .options(vec!["1x".to_string(), "2x".to_string(), "4x".to_string()])
+        .selected(1)
+        .on_select(|i| {
+            GameAction::SetSpeed([GameSpeed::Normal, GameSpeed::Fast, GameSpeed::Fastest][i]).into()
+        })
+        .build()
+}
+
+/// Slider from 0 to 100 in steps of 10 whose track occupies columns 0-10.
+fn slider(value: u32) -> impl FnMut() -> Slider {
+    move || {
+        Slider::new()
+            .id(ID)
+            .value(value)
+            .min(0)
+            .max(100)
+            .step(10)
+            .on_change(choose)
+            .focused(true)
+            .build()
+    }
+}
+
+#[test]
+fn checkbox_style() {
+    let mut harness = TestHarness::new(20, 1);
+    harness.render(checkbox);
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn checkbox_click() {
+    let mut harness = TestHarness::new(20, 1);
+    assert_eq!(harness.click(1, 0, checkbox), vec![InterfaceAction::CloseModal]);
+}
+
+#[test]
+fn radio_group_style() {
+    let mut harness = TestHarness::new(10, 3);
+    harness.render(radio_group);
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn radio_group_click() {
+    let mut harness = TestHarness::new(10, 3);
+    assert_eq!(harness.click(1, 2, radio_group), vec![
+        GameAction::SetSpeed(GameSpeed::Fastest).into()
+    ]);
+}
+
+#[test]
+fn slider_style() {
+    let mut harness = TestHarness::new(15, 1);
+    harness.render(slider(40));
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn slider_click() {
+    let mut harness = TestHarness::new(15, 1);
+    harness.send(events::mouse_down(7, 0));
+    assert_eq!(harness.render(slider(40)), vec![choose(70)]);
+}
+
+#[test]
+fn slider_drag() {
+    let mut harness = TestHarness::new(15, 1);
+    harness.send(events::mouse_down(4, 0));
+    harness.render(slider(40));
+    harness.send(events::mouse_drag(5, 0));
+    harness.send(events::mouse_drag(6, 0));
+    assert_eq!(harness.render(slider(40)), vec![choose(60)]);
+
+    // Dragging continues to update the value outside of the slider
+    harness.send(events::mouse_drag(14, 3));
+    assert_eq!(harness.render(slider(60)), vec![choose(100)]);
+
+    harness.send(events::mouse_up(14, 3));
+    harness.render(slider(100));
+    harness.send(events::mouse_drag(2, 0));
+    assert!(harness.render(slider(100)).is_empty());
+}
+
+#[test]
+fn slider_drag_must_start_on_track() {
+    let mut harness = TestHarness::new(20, 3).with_widget_area(Rect::new(0, 0, 15, 1));
+    harness.send(events::mouse_down(5, 2));
+    harness.send(events::mouse_drag(5, 0));
+    assert!(harness.render(slider(40)).is_empty());
+}
+
+#[test]
+fn slider_keys() {
+    let mut harness = TestHarness::new(15, 1);
+    assert_eq!(harness.key(KeyCode::Right, slider(40)), vec![choose(50)]);
+    assert_eq!(harness.key(KeyCode::Left, slider(40)), vec![choose(30)]);
+    assert_eq!(harness.key(KeyCode::End, slider(40)), vec![choose(100)]);
+    assert!(harness.key(KeyCode::Right, slider(100)).is_empty());
+}
+
+#[test]
+fn slider_with_empty_range() {
+    let mut harness = TestHarness::new(15, 1);
+    let slider =
+        || Slider::new().id(ID).value(5).min(10).max(5).on_change(choose).focused(true).build();
+    assert!(harness.key(KeyCode::Right, slider).is_empty());
+    assert_eq!(harness.key(KeyCode::Home, slider), vec![choose(10)]);
+}
+
+#[test]
+fn slider_formats_value() {
+    let mut harness = TestHarness::new(15, 1);
+    harness.render(|| {
+        Slider::new()
+            .id(ID)
+            .value(1)
+            .min(0)
+            .max(2)
+            .on_change(choose)
+            .format(|i| format!("{}x", 1 << i))
+            .build()
+    });
+    assert!(harness.displays("2x"));
+}
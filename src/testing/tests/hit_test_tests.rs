@@ -0,0 +1,239 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::{Cell, RefCell};
+
+use crossterm::event::KeyCode;
+use data::core::actions::InterfaceAction;
+use data::core::map::TilePosition;
+use data::core::widget_id::{WidgetId, WidgetName};
+use display::core::button::Button;
+use display::core::checkbox::Checkbox;
+use display::core::render_context::RenderContext;
+use display::core::scale::{GridView, Scale};
+use display::core::slider::Slider;
+use ratatui::layout::Size;
+use ratatui::prelude::*;
+use testing::harness::TestHarness;
+
+/// Position of a widget in the stacks of overlapping widgets rendered by
+/// these tests, which determines its ID, action and hotkey.
+#[derive(Debug, Copy, Clone)]
+enum Layer {
+    Bottom,
+    Top,
+}
+
+impl Layer {
+    /// Layers are told apart by index within a single parent, so that no ID
+    /// is mistaken for that of a real widget.
+    fn id(self) -> WidgetId {
+        WidgetId::new(WidgetName::MainMenu).index(self as usize)
+    }
+
+    fn action(self) -> InterfaceAction {
+        match self {
+            Layer::Bottom => InterfaceAction::NewGame,
+            Layer::Top => InterfaceAction::Exit,
+        }
+    }
+
+    fn hotkey(self) -> KeyCode {
+        match self {
+            Layer::Bottom => KeyCode::Char('b'),
+            Layer::Top => KeyCode::Char('t'),
+        }
+    }
+
+    fn button(self) -> Button {
+        Button::new()
+            .label(format!("{self:?}"))
+            .action(self.action())
+            .id(self.id())
+            .hotkey(self.hotkey())
+            .build()
+    }
+}
+
+/// Renders two overlapping buttons, optionally with the top button in a modal
+/// layer. The bottom button can be omitted.
+struct Overlapping {
+    modal: bool,
+    bottom: bool,
+}
+
+impl StatefulWidget for Overlapping {
+    type State = RenderContext;
+
+    fn render(self, _: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        if self.bottom {
+            Layer::Bottom.button().render(Rect::new(0, 0, 12, 3), buf, context);
+        }
+        if self.modal {
+            context.modal_layer();
+        }
+        Layer::Top.button().render(Rect::new(6, 1, 12, 3), buf, context);
+    }
+}
+
+fn overlapping() -> Overlapping {
+    Overlapping { modal: false, bottom: true }
+}
+
+fn modal() -> Overlapping {
+    Overlapping { modal: true, bottom: true }
+}
+
+#[test]
+fn hover_topmost() {
+    let mut harness = TestHarness::new(20, 5);
+    harness.render(overlapping);
+    harness.hover(8, 2, overlapping);
+    assert_eq!(harness.context().current_hover(), Some(Layer::Top.id()));
+}
+
+#[test]
+fn hover_uncovered_area() {
+    let mut harness = TestHarness::new(20, 5);
+    harness.render(overlapping);
+    harness.hover(1, 1, overlapping);
+    assert_eq!(harness.context().current_hover(), Some(Layer::Bottom.id()));
+}
+
+#[test]
+fn click_topmost() {
+    let mut harness = TestHarness::new(20, 5);
+    harness.render(overlapping);
+    assert_eq!(harness.click(8, 2, overlapping), vec![Layer::Top.action()]);
+    assert_eq!(harness.click(1, 1, overlapping), vec![Layer::Bottom.action()]);
+}
+
+#[test]
+fn modal_blocks_input() {
+    let mut harness = TestHarness::new(20, 5);
+    harness.render(modal);
+    harness.hover(1, 1, modal);
+    assert_eq!(harness.context().current_hover(), None);
+    assert!(harness.click(1, 1, modal).is_empty());
+    assert_eq!(harness.click(8, 2, modal), vec![Layer::Top.action()]);
+}
+
+#[test]
+fn modal_blocks_keys() {
+    let mut harness = TestHarness::new(20, 5);
+    harness.render(overlapping);
+    assert_eq!(harness.key(Layer::Bottom.hotkey(), overlapping), vec![Layer::Bottom.action()]);
+    harness.render(modal);
+    assert!(harness.key(Layer::Bottom.hotkey(), modal).is_empty());
+    assert_eq!(harness.key(Layer::Top.hotkey(), modal), vec![Layer::Top.action()]);
+}
+
+#[test]
+fn modal_blocks_input_to_newly_rendered_widget() {
+    let mut harness = TestHarness::new(20, 5);
+    harness.render(|| Overlapping { modal: true, bottom: false });
+    assert!(harness.click(1, 1, modal).is_empty());
+}
+
+/// Renders a button with another widget on top of its middle row.
+struct Covered<W> {
+    cover: W,
+}
+
+impl<W: StatefulWidget<State = RenderContext>> StatefulWidget for Covered<W> {
+    type State = RenderContext;
+
+    fn render(self, _: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        Layer::Bottom.button().render(Rect::new(0, 0, 12, 3), buf, context);
+        self.cover.render(Rect::new(0, 1, 12, 1), buf, context);
+    }
+}
+
+#[test]
+fn controls_block_widgets_beneath() {
+    let mut harness = TestHarness::new(20, 5);
+    let checkbox = || Covered {
+        cover: Checkbox::new()
+            .label("Top")
+            .checked(false)
+            .action(Layer::Top.action())
+            .id(Layer::Top.id())
+            .build(),
+    };
+    assert_eq!(harness.click(1, 1, checkbox), vec![Layer::Top.action()]);
+
+    let slider = || Covered {
+        cover: Slider::new()
+            .id(Layer::Top.id())
+            .value(0)
+            .min(0)
+            .max(10)
+            .on_change(|value| {
+                InterfaceAction::SelectTower(Some(TilePosition::new(value as u16, 0)))
+            })
+            .build(),
+    };
+    harness.hover(4, 1, slider);
+    assert_eq!(harness.context().current_hover(), Some(Layer::Top.id()));
+    assert_eq!(harness.click(1, 0, slider), vec![Layer::Bottom.action()]);
+}
+
+/// Grid of 3x2 tiles drawn at [Scale::WIDE] which records the tiles clicked
+/// and hovered.
+struct Tiles<'a> {
+    clicked: &'a RefCell<Vec<TilePosition>>,
+    hovered: &'a Cell<Option<TilePosition>>,
+}
+
+impl<'a> StatefulWidget for Tiles<'a> {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, _: &mut Buffer, context: &mut RenderContext) {
+        let view =
+            GridView { origin: area.as_position(), grid: Size::new(3, 2), scale: Scale::WIDE };
+        let id = Layer::Bottom.id();
+        context.register(id, view.area());
+        context.mouse_down(id, view.area());
+        let hovered = context.hovered(id, view.area());
+        self.hovered
+            .set(context.mouse_position().filter(|_| hovered).and_then(|p| view.tile_at(p)));
+        self.clicked.borrow_mut().extend(
+            context.click_positions(id, view.area()).into_iter().filter_map(|p| view.tile_at(p)),
+        );
+    }
+}
+
+#[test]
+fn click_tiles() {
+    let clicked = RefCell::new(vec![]);
+    let hovered = Cell::new(None);
+    let mut harness = TestHarness::new(10, 4).with_widget_area(Rect::new(2, 1, 6, 2));
+    let tiles = || Tiles { clicked: &clicked, hovered: &hovered };
+    harness.click(3, 1, tiles);
+    harness.click(7, 2, tiles);
+    harness.click(8, 2, tiles);
+    assert_eq!(*clicked.borrow(), vec![TilePosition::new(0, 0), TilePosition::new(2, 1)]);
+}
+
+#[test]
+fn hover_tile() {
+    let clicked = RefCell::new(vec![]);
+    let hovered = Cell::new(None);
+    let mut harness = TestHarness::new(10, 4).with_widget_area(Rect::new(2, 1, 6, 2));
+    let tiles = || Tiles { clicked: &clicked, hovered: &hovered };
+    harness.hover(5, 2, tiles);
+    assert_eq!(hovered.get(), Some(TilePosition::new(1, 1)));
+    harness.hover(0, 0, tiles);
+    assert_eq!(hovered.get(), None);
+}
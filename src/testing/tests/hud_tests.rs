@@ -0,0 +1,156 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::GameAction;
+use data::core::colors;
+use data::core::enemy::EnemyKind;
+use data::core::game_state::{GameSpeed, GameState};
+use data::core::wave::Wave;
+use display::core::animation::FLASH_DURATION;
+use display::game::hud::Hud;
+use rules::core::{enemies, new_game};
+use testing::harness::TestHarness;
+use testing::screen;
+
+fn hud(state: &GameState) -> Hud<'_> {
+    Hud { state, paused: false }
+}
+
+#[test]
+fn new_game() {
+    let state = new_game::create(Default::default());
+    let mut harness = TestHarness::new(80, 1);
+    harness.render(|| hud(&state));
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn countdown() {
+    let mut state = new_game::create(Default::default());
+    let mut harness = TestHarness::new(80, 1);
+    harness.render(|| hud(&state));
+    assert!(harness.displays("Next 30s"));
+    state.tick = 599;
+    harness.render(|| hud(&state));
+    assert!(harness.displays("Next 1s"));
+    state.next_wave_tick = None;
+    harness.render(|| hud(&state));
+    assert!(!harness.displays("Next"));
+}
+
+#[test]
+fn countdown_at_game_speed() {
+    let mut state = new_game::create(GameSpeed::Fast);
+    let mut harness = TestHarness::new(80, 1);
+    harness.render(|| hud(&state));
+    assert!(harness.displays("Next 15s"));
+    state.speed = GameSpeed::Fastest;
+    state.tick = 599;
+    harness.render(|| hud(&state));
+    assert!(harness.displays("Next 1s"));
+}
+
+#[test]
+fn new_game_does_not_flash() {
+    let mut state = new_game::create(Default::default());
+    let mut harness = TestHarness::new(80, 1);
+    state.gold = 500;
+    harness.render(|| hud(&state));
+    let state = new_game::create(Default::default());
+    harness.context().start_new_game();
+    harness.render(|| hud(&state));
+    let (x, y) = screen::find(harness.buffer(), "150").unwrap();
+    assert_eq!(harness.buffer().get(x, y).fg, colors::yellow());
+}
+
+#[test]
+fn send_wave_early() {
+    let state = new_game::create(Default::default());
+    let mut harness = TestHarness::new(80, 1);
+    harness.render(|| hud(&state));
+    assert!(harness.displays("[n] Send +30g"));
+    assert_eq!(harness.key(KeyCode::Char('n'), || hud(&state)), vec![
+        GameAction::SendWaveEarly.into()
+    ]);
+}
+
+#[test]
+fn paused() {
+    let state = new_game::create(Default::default());
+    let mut harness = TestHarness::new(80, 1);
+    harness.render(|| Hud { state: &state, paused: true });
+    assert!(harness.displays("Paused"));
+    assert!(!harness.displays("1x"));
+}
+
+#[test]
+fn large_values_fit() {
+    let mut state = new_game::create(GameSpeed::Fastest);
+    state.gold = 999_999;
+    state.lives = 999;
+    state.wave = 99;
+    state.waves = vec![Wave::default(); 99];
+    state.next_wave_tick = Some(99_999);
+    state.score = 99_999_999;
+    let mut harness = TestHarness::new(80, 1);
+    harness.render(|| hud(&state));
+    assert!(harness.displays("Score 99999999"));
+    assert!(harness.displays("▶▶▶ 4x"));
+}
+
+#[test]
+fn lives_flash_red_when_lost() {
+    let mut state = new_game::create(Default::default());
+    let mut harness = TestHarness::new(80, 1);
+    harness.render(|| hud(&state));
+    let (x, y) = screen::find(harness.buffer(), "20").unwrap();
+    assert_eq!(harness.buffer().get(x, y).fg, colors::white());
+
+    state.lives = 19;
+    harness.render(|| hud(&state));
+    assert_eq!(harness.buffer().get(x, y).fg, colors::red());
+    assert!(harness.context().redraw_at().is_some());
+
+    harness.advance(FLASH_DURATION);
+    harness.render(|| hud(&state));
+    assert_eq!(harness.buffer().get(x, y).fg, colors::white());
+    assert!(harness.context().redraw_at().is_none());
+}
+
+#[test]
+fn gold_flashes_green_when_earned() {
+    let mut state = new_game::create(Default::default());
+    let mut harness = TestHarness::new(80, 1);
+    harness.render(|| hud(&state));
+    state.gold += 25;
+    harness.render(|| hud(&state));
+    let (x, y) = screen::find(harness.buffer(), "175").unwrap();
+    assert_eq!(harness.buffer().get(x, y).fg, colors::light_green());
+}
+
+#[test]
+fn boss_health_bar() {
+    let mut state = new_game::create(Default::default());
+    let mut harness = TestHarness::new(100, 1);
+    harness.render(|| hud(&state));
+    assert!(!harness.displays("Warlord"));
+
+    enemies::spawn(&mut state, EnemyKind::Warlord, 0);
+    state.enemies[0].health = state.enemies[0].max_health / 2;
+    state.enemies[0].phase = 2;
+    harness.render(|| hud(&state));
+    assert!(harness.displays("W Warlord P3"));
+    assert!(harness.displays("█"));
+}
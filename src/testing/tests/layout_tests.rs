@@ -0,0 +1,264 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::map::TilePosition;
+use display::core::layout;
+use display::core::layout::{Edge, GameLayout, Insets, ScreenLayout, ScreenOptions};
+use display::core::render_context::RenderContext;
+use display::core::scale::{GridView, Scale};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect, Size};
+
+const PANELS: ScreenOptions = ScreenOptions { side_panels: true, scale_map: false };
+const SCALED: ScreenOptions = ScreenOptions { side_panels: true, scale_map: true };
+
+const AREA: Rect = Rect { x: 2, y: 3, width: 20, height: 10 };
+
+#[test]
+fn centered_rect() {
+    let rect = layout::centered_rect(Size { width: 10, height: 4 }, AREA);
+    assert_eq!(rect, Rect::new(7, 6, 10, 4));
+}
+
+#[test]
+fn centered_rect_larger_than_area() {
+    let rect = layout::centered_rect(Size { width: 30, height: 40 }, AREA);
+    assert_eq!(rect, AREA);
+}
+
+#[test]
+fn centered_rect_in_empty_area() {
+    let rect = layout::centered_rect(Size { width: 10, height: 4 }, Rect::new(5, 5, 0, 0));
+    assert_eq!(rect.area(), 0);
+}
+
+#[test]
+fn anchor_edges() {
+    assert_eq!(
+        layout::anchor(AREA, Edge::Top, 2),
+        (Rect::new(2, 3, 20, 2), Rect::new(2, 5, 20, 8))
+    );
+    assert_eq!(
+        layout::anchor(AREA, Edge::Bottom, 2),
+        (Rect::new(2, 11, 20, 2), Rect::new(2, 3, 20, 8))
+    );
+    assert_eq!(
+        layout::anchor(AREA, Edge::Left, 5),
+        (Rect::new(2, 3, 5, 10), Rect::new(7, 3, 15, 10))
+    );
+    assert_eq!(
+        layout::anchor(AREA, Edge::Right, 5),
+        (Rect::new(17, 3, 5, 10), Rect::new(2, 3, 15, 10))
+    );
+}
+
+#[test]
+fn anchor_larger_than_area() {
+    assert_eq!(layout::anchor(AREA, Edge::Top, 50), (AREA, Rect::new(2, 13, 20, 0)));
+    assert_eq!(layout::anchor(AREA, Edge::Right, 50), (AREA, Rect::new(2, 3, 0, 10)));
+}
+
+#[test]
+fn anchor_in_empty_area() {
+    let empty = Rect::new(4, 4, 0, 0);
+    assert_eq!(layout::anchor(empty, Edge::Bottom, 3), (empty, empty));
+    assert_eq!(layout::anchor(empty, Edge::Left, 3), (empty, empty));
+}
+
+#[test]
+fn inset() {
+    assert_eq!(layout::inset(AREA, Insets::uniform(1)), Rect::new(3, 4, 18, 8));
+    assert_eq!(layout::inset(AREA, Insets::symmetric(2, 0)), Rect::new(4, 3, 16, 10));
+    assert_eq!(
+        layout::inset(AREA, Insets { top: 1, right: 2, bottom: 3, left: 4 }),
+        Rect::new(6, 4, 14, 6)
+    );
+}
+
+#[test]
+fn inset_larger_than_area() {
+    let rect = layout::inset(AREA, Insets::uniform(u16::MAX));
+    assert_eq!(rect.area(), 0);
+    assert_eq!(AREA.union(rect), AREA);
+}
+
+#[test]
+fn grid() {
+    let cells = layout::grid(Rect::new(0, 0, 21, 7), 2, 2, 1);
+    assert_eq!(cells, vec![
+        Rect::new(0, 0, 10, 3),
+        Rect::new(11, 0, 10, 3),
+        Rect::new(0, 4, 10, 3),
+        Rect::new(11, 4, 10, 3),
+    ]);
+}
+
+#[test]
+fn grid_without_cells() {
+    assert!(layout::grid(AREA, 0, 3, 0).is_empty());
+    assert!(layout::grid(AREA, 3, 0, 0).is_empty());
+}
+
+#[test]
+fn grid_in_empty_area() {
+    let cells = layout::grid(Rect::new(1, 1, 0, 0), 2, 3, 1);
+    assert_eq!(cells.len(), 6);
+    assert!(cells.iter().all(|c| c.area() == 0));
+}
+
+#[test]
+fn game_layout() {
+    let layout = GameLayout::new(Rect::new(0, 0, 80, 24));
+    assert_eq!(layout.hud, Rect::new(0, 0, 80, 1));
+    assert_eq!(layout.status, Rect::new(0, 23, 80, 1));
+    assert_eq!(layout.shop, Rect::new(60, 1, 20, 22));
+    assert_eq!(layout.battlefield, Rect::new(0, 1, 60, 22));
+}
+
+#[test]
+fn debug_overlay() {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 6));
+    let mut context = RenderContext::default();
+    context.start_debug_overlay();
+    context.centered_rect(Size { width: 8, height: 4 }, buffer.area);
+    context.grid(Rect::new(0, 0, 0, 0), 1, 1, 0);
+    layout::centered_rect(Size { width: 2, height: 2 }, buffer.area);
+    context.render_debug_overlay(&mut buffer);
+    insta::assert_snapshot!(format!("{buffer:?}"));
+
+    // Recording stops after rendering
+    context.centered_rect(Size { width: 2, height: 2 }, buffer.area);
+    let mut empty = Buffer::empty(buffer.area);
+    context.render_debug_overlay(&mut empty);
+    assert_eq!(empty, Buffer::empty(buffer.area));
+}
+
+#[test]
+fn screen_layout_too_small() {
+    assert_eq!(ScreenLayout::new(Rect::new(0, 0, 79, 40), PANELS), None);
+    assert_eq!(ScreenLayout::new(Rect::new(0, 0, 200, 23), PANELS), None);
+}
+
+#[test]
+fn screen_layout_fixed() {
+    let layout = ScreenLayout::new(Rect::new(0, 0, 81, 30), PANELS).unwrap();
+    assert_eq!(layout.game, Rect::new(1, 3, 80, 24));
+    assert_eq!(layout.outline, None);
+    assert_eq!(layout.left_panel, None);
+    assert_eq!(layout.right_panel, None);
+}
+
+#[test]
+fn screen_layout_outline() {
+    let layout = ScreenLayout::new(Rect::new(0, 0, 100, 26), PANELS).unwrap();
+    assert_eq!(layout.outline, Some(Rect::new(9, 0, 82, 26)));
+    assert_eq!(layout.game, Rect::new(10, 1, 80, 24));
+    assert_eq!(layout.right_panel, None);
+}
+
+#[test]
+fn screen_layout_right_panel() {
+    let layout = ScreenLayout::new(Rect::new(0, 0, 120, 30), PANELS).unwrap();
+    assert_eq!(layout.outline, Some(Rect::new(4, 2, 82, 26)));
+    assert_eq!(layout.right_panel, Some(Rect::new(86, 2, 30, 26)));
+    assert_eq!(layout.left_panel, None);
+}
+
+#[test]
+fn screen_layout_both_panels() {
+    let layout = ScreenLayout::new(Rect::new(0, 0, 142, 26), PANELS).unwrap();
+    assert_eq!(layout.left_panel, Some(Rect::new(0, 0, 30, 26)));
+    assert_eq!(layout.outline, Some(Rect::new(30, 0, 82, 26)));
+    assert_eq!(layout.right_panel, Some(Rect::new(112, 0, 30, 26)));
+    assert_eq!(layout.game, Rect::new(31, 1, 80, 24));
+}
+
+#[test]
+fn screen_layout_without_side_panels() {
+    let layout = ScreenLayout::new(Rect::new(0, 0, 142, 26), ScreenOptions::default()).unwrap();
+    assert_eq!(layout.left_panel, None);
+    assert_eq!(layout.right_panel, None);
+    assert_eq!(layout.game, Rect::new(31, 1, 80, 24));
+}
+
+#[test]
+fn normal_scale_matches_game_size() {
+    assert_eq!(layout::game_size(Scale::NORMAL), Size { width: 80, height: 24 });
+}
+
+#[test]
+fn screen_layout_scaling_disabled() {
+    let layout = ScreenLayout::new(Rect::new(0, 0, 300, 80), PANELS).unwrap();
+    assert_eq!(layout.scale, Scale::NORMAL);
+    assert_eq!(layout.game.as_size(), Size { width: 80, height: 24 });
+}
+
+#[test]
+fn screen_layout_scaled() {
+    let layout = ScreenLayout::new(Rect::new(0, 0, 150, 30), SCALED).unwrap();
+    assert_eq!(layout.scale, Scale::WIDE);
+    assert_eq!(layout.game, Rect::new(5, 3, 140, 24));
+    assert_eq!(layout.outline, Some(Rect::new(4, 2, 142, 26)));
+    assert_eq!(layout.right_panel, None);
+
+    let layout = ScreenLayout::new(Rect::new(0, 0, 260, 46), SCALED).unwrap();
+    assert_eq!(layout.scale, Scale::LARGE);
+    assert_eq!(layout.game.as_size(), Size { width: 260, height: 46 });
+    assert_eq!(layout.outline, None);
+}
+
+#[test]
+fn screen_layout_scaling_falls_back_to_normal() {
+    let layout = ScreenLayout::new(Rect::new(0, 0, 139, 60), SCALED).unwrap();
+    assert_eq!(layout.scale, Scale::NORMAL);
+    assert!(layout.right_panel.is_some());
+}
+
+#[test]
+fn grid_view_mapping() {
+    let view = GridView {
+        origin: Position::new(10, 5),
+        grid: Size { width: 3, height: 2 },
+        scale: Scale::LARGE,
+    };
+    assert_eq!(view.area(), Rect::new(10, 5, 12, 4));
+    assert_eq!(view.tile_area(TilePosition::new(2, 1)), Rect::new(18, 7, 4, 2));
+    assert_eq!(view.tile_at(Position::new(10, 5)), Some(TilePosition::new(0, 0)));
+    assert_eq!(view.tile_at(Position::new(13, 6)), Some(TilePosition::new(0, 0)));
+    assert_eq!(view.tile_at(Position::new(14, 6)), Some(TilePosition::new(1, 0)));
+    assert_eq!(view.tile_at(Position::new(21, 8)), Some(TilePosition::new(2, 1)));
+}
+
+#[test]
+fn grid_view_outside() {
+    let view = GridView {
+        origin: Position::new(10, 5),
+        grid: Size { width: 3, height: 2 },
+        scale: Scale::WIDE,
+    };
+    assert_eq!(view.tile_at(Position::new(9, 5)), None);
+    assert_eq!(view.tile_at(Position::new(16, 5)), None);
+    assert_eq!(view.tile_at(Position::new(10, 7)), None);
+}
+
+#[test]
+fn empty_grid_view() {
+    let view = GridView {
+        origin: Position::new(0, 0),
+        grid: Size { width: 0, height: 0 },
+        scale: Scale::WIDE,
+    };
+    assert_eq!(view.area().area(), 0);
+    assert_eq!(view.tile_at(Position::new(0, 0)), None);
+}
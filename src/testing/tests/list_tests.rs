@@ -0,0 +1,157 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::{GameAction, InterfaceAction};
+use data::core::map::TilePosition;
+use data::core::widget_id::{WidgetId, WidgetName};
+use display::core::list::List;
+use display::core::table::Table;
+use ratatui::prelude::*;
+use testing::events;
+use testing::harness::TestHarness;
+
+const ID: WidgetId = WidgetId::new(WidgetName::Upgrade);
+
+/// Lists behave like the upgrade choices of the tower at the origin.
+fn select(index: usize) -> InterfaceAction {
+    GameAction::UpgradeTower(TilePosition::new(0, 0), index).into()
+}
+
+fn list(selected: Option<usize>, focused: bool) -> impl FnMut() -> List {
+    move || {
+        List::new()
+            .id(ID)
+            .items((0..10).map(|i| Line::from(format!("Item {i}"))).collect())
+            .selected(selected)
+            .on_select(select)
+            .focused(focused)
+            .build()
+    }
+}
+
+fn table() -> Table {
+    Table::new()
+        .id(ID)
+        .header(vec!["Slot".to_string(), "Wave".to_string()])
+        .widths(vec![Constraint::Length(6), Constraint::Fill(1)])
+        .rows(
+            (0..3)
+                .map(|i| vec![Line::from(format!("Save {i}")), Line::from(format!("{}", i * 5))])
+                .collect(),
+        )
+        .selected(Some(1))
+        .on_select(select)
+        .build()
+}
+
+#[test]
+fn list_with_scrollbar() {
+    let mut harness = TestHarness::new(12, 4);
+    harness.render(list(Some(1), false));
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn row_hover() {
+    let mut harness = TestHarness::new(12, 4);
+    harness.hover(2, 2, list(None, false));
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn wheel_scroll() {
+    let mut harness = TestHarness::new(12, 4);
+    harness.render(list(None, false));
+    harness.send(events::scroll_down(2, 2));
+    harness.send(events::scroll_down(2, 2));
+    harness.render(list(None, false));
+    assert!(!harness.displays("Item 1"));
+    assert!(harness.displays("Item 2"));
+    assert!(harness.displays("Item 5"));
+
+    harness.send(events::scroll_up(2, 2));
+    harness.render(list(None, false));
+    assert!(harness.displays("Item 1"));
+}
+
+#[test]
+fn wheel_scroll_stops_at_end() {
+    let mut harness = TestHarness::new(12, 4);
+    harness.render(list(None, false));
+    for _ in 0..20 {
+        harness.send(events::scroll_down(2, 2));
+    }
+    harness.render(list(None, false));
+    assert!(harness.displays("Item 6"));
+    assert!(harness.displays("Item 9"));
+    assert_eq!(harness.context().scroll_offset(ID), 6);
+}
+
+#[test]
+fn click_selects() {
+    let mut harness = TestHarness::new(12, 4);
+    assert_eq!(harness.click(2, 3, list(None, false)), vec![select(3)]);
+}
+
+#[test]
+fn click_selects_scrolled_row() {
+    let mut harness = TestHarness::new(12, 4);
+    harness.render(list(None, false));
+    harness.send(events::scroll_down(2, 2));
+    harness.render(list(None, false));
+    assert_eq!(harness.click(2, 0, list(None, false)), vec![select(1)]);
+}
+
+#[test]
+fn keyboard_navigation() {
+    let mut harness = TestHarness::new(12, 4);
+    assert_eq!(harness.key(KeyCode::Down, list(Some(0), true)), vec![select(1)]);
+    assert_eq!(harness.key(KeyCode::Up, list(Some(3), true)), vec![select(2)]);
+    assert_eq!(harness.key(KeyCode::Down, list(None, true)), vec![select(0)]);
+    assert_eq!(harness.key(KeyCode::PageDown, list(Some(0), true)), vec![select(4)]);
+    assert!(harness.key(KeyCode::Up, list(Some(0), true)).is_empty());
+}
+
+#[test]
+fn keyboard_navigation_scrolls_to_selection() {
+    let mut harness = TestHarness::new(12, 4);
+    assert_eq!(harness.key(KeyCode::End, list(Some(0), true)), vec![select(9)]);
+    harness.render(list(Some(9), true));
+    assert!(harness.displays("Item 9"));
+    assert!(!harness.displays("Item 5"));
+
+    assert_eq!(harness.key(KeyCode::Home, list(Some(9), true)), vec![select(0)]);
+    harness.render(list(Some(0), true));
+    assert!(harness.displays("Item 0"));
+}
+
+#[test]
+fn unfocused_ignores_keys() {
+    let mut harness = TestHarness::new(12, 4);
+    assert!(harness.key(KeyCode::Down, list(Some(0), false)).is_empty());
+}
+
+#[test]
+fn table_with_header() {
+    let mut harness = TestHarness::new(16, 4);
+    harness.render(table);
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn table_click_selects() {
+    let mut harness = TestHarness::new(16, 4);
+    assert_eq!(harness.click(2, 1, table), vec![select(0)]);
+}
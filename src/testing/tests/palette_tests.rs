@@ -0,0 +1,37 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::colors::Palette;
+use data::core::settings::{ColorDepth, Theme};
+use ratatui::style::Color;
+
+fn ansi_256(color: Color) -> Color {
+    Palette { theme: Theme::Standard, color_depth: ColorDepth::Ansi256 }.apply(color)
+}
+
+#[test]
+fn ansi_256_uses_xterm_cube_levels() {
+    assert_eq!(ansi_256(Color::Rgb(0, 0, 0)), Color::Indexed(16));
+    assert_eq!(ansi_256(Color::Rgb(255, 255, 255)), Color::Indexed(231));
+    assert_eq!(ansi_256(Color::Rgb(95, 135, 175)), Color::Indexed(67));
+    // Closest to the second level, 95, rather than the third, 135
+    assert_eq!(ansi_256(Color::Rgb(100, 100, 100)), Color::Indexed(59));
+    assert_eq!(ansi_256(Color::Rgb(114, 116, 40)), Color::Indexed(64));
+}
+
+#[test]
+fn non_rgb_colors_unchanged() {
+    assert_eq!(ansi_256(Color::Reset), Color::Reset);
+    assert_eq!(ansi_256(Color::Indexed(3)), Color::Indexed(3));
+}
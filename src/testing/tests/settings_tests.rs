@@ -0,0 +1,36 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::game_state::GameSpeed;
+use data::core::settings::{AutosaveFrequency, Settings, Theme};
+
+#[test]
+fn settings_round_trip_through_toml() {
+    let mut settings = Settings {
+        game_speed: GameSpeed::Fast,
+        theme: Theme::Grayscale,
+        autosave: AutosaveFrequency::EveryFiveWaves,
+        ..Settings::default()
+    };
+    settings.key_bindings.pause = KeyCode::Char('x');
+    let text = toml::to_string_pretty(&settings).unwrap();
+    assert_eq!(toml::from_str::<Settings>(&text).unwrap(), settings);
+}
+
+#[test]
+fn missing_settings_use_defaults() {
+    let settings = toml::from_str::<Settings>("autosave = \"Never\"").unwrap();
+    assert_eq!(settings, Settings { autosave: AutosaveFrequency::Never, ..Settings::default() });
+}
@@ -0,0 +1,80 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::InterfaceAction;
+use data::core::game_state::GameState;
+use data::core::interface_state::Tool;
+use data::core::tower::TowerKind;
+use display::game::shop::Shop;
+use rules::core::new_game;
+use testing::harness::TestHarness;
+
+fn shop(state: &GameState, tool: Tool) -> Shop<'_> {
+    Shop { state, tool }
+}
+
+#[test]
+fn shop_panel() {
+    let state = new_game::create(Default::default());
+    let mut harness = TestHarness::new(20, 22);
+    harness.render(|| shop(&state, Tool::Build(TowerKind::Arrow)));
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn hotkey_enters_placement_mode() {
+    let state = new_game::create(Default::default());
+    let mut harness = TestHarness::new(20, 22);
+    let actions = harness.key(KeyCode::Char('3'), || shop(&state, Tool::Select));
+    assert_eq!(actions, vec![InterfaceAction::SetTool(Tool::Build(TowerKind::Frost))]);
+}
+
+#[test]
+fn click_enters_placement_mode() {
+    let state = new_game::create(Default::default());
+    let mut harness = TestHarness::new(20, 22);
+    let actions = harness.click(8, 3, || shop(&state, Tool::Select));
+    assert_eq!(actions, vec![InterfaceAction::SetTool(Tool::Build(TowerKind::Arrow))]);
+}
+
+#[test]
+fn click_selected_entry_cancels_placement() {
+    let state = new_game::create(Default::default());
+    let mut harness = TestHarness::new(20, 22);
+    let actions = harness.click(8, 3, || shop(&state, Tool::Build(TowerKind::Arrow)));
+    assert_eq!(actions, vec![InterfaceAction::SetTool(Tool::Select)]);
+}
+
+#[test]
+fn unaffordable_tower_disabled() {
+    let mut state = new_game::create(Default::default());
+    state.gold = 60;
+    let mut harness = TestHarness::new(20, 22);
+    assert!(harness.key(KeyCode::Char('2'), || shop(&state, Tool::Select)).is_empty());
+    assert!(harness.click(8, 7, || shop(&state, Tool::Select)).is_empty());
+    assert_eq!(harness.key(KeyCode::Char('1'), || shop(&state, Tool::Select)), vec![
+        InterfaceAction::SetTool(Tool::Build(TowerKind::Arrow))
+    ]);
+}
+
+#[test]
+fn only_unlocked_towers_listed() {
+    let mut state = new_game::create(Default::default());
+    state.unlocked_towers = vec![TowerKind::Cannon];
+    let mut harness = TestHarness::new(20, 22);
+    harness.render(|| shop(&state, Tool::Select));
+    assert!(harness.displays("Cannon"));
+    assert!(!harness.displays("Arrow"));
+}
@@ -0,0 +1,153 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::colors;
+use data::core::enemy::EnemyKind;
+use data::core::game_event::GameEvent;
+use data::core::game_state::GameState;
+use data::core::map::{TilePosition, MAP_WIDTH, PATH_UNITS_PER_TILE};
+use data::core::tower::{Tower, TowerKind};
+use display::panels::event_log::{EventHistory, EventLog, EVENT_HISTORY_LENGTH};
+use display::panels::minimap::Minimap;
+use display::panels::tower_details::TowerDetails;
+use ratatui::prelude::*;
+use rules::core::{enemies, new_game, towers, waves};
+use testing::harness::TestHarness;
+use testing::screen;
+
+const GRASS: TilePosition = TilePosition::new(4, 4);
+
+fn quiet_game() -> GameState {
+    let mut state = new_game::create(Default::default());
+    state.next_wave_tick = None;
+    state
+}
+
+/// Renders an [EventLog] listing `history` into a new buffer.
+fn event_log(history: &EventHistory, width: u16, height: u16) -> Buffer {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+    EventLog { history }.render(buffer.area, &mut buffer);
+    buffer
+}
+
+#[test]
+fn event_log_lists_notable_events() {
+    let mut state = quiet_game();
+    let mut history = EventHistory::default();
+    let buffer = event_log(&history, 28, 6);
+    assert!(screen::find(&buffer, "Nothing has happened yet").is_some());
+
+    state.next_wave_tick = Some(100);
+    waves::send_early(&mut state);
+    towers::build(&mut state, TowerKind::Arrow, GRASS);
+    state.events.push(GameEvent::EnemyDamaged { position: GRASS, amount: 5 });
+    state.events.push(GameEvent::EnemyDefeated {
+        kind: EnemyKind::Grunt,
+        position: GRASS,
+        bounty: 3,
+    });
+    state.events.push(GameEvent::EnemyEscaped { kind: EnemyKind::Runner });
+    history.observe(&state);
+    insta::assert_snapshot!(format!("{:?}", event_log(&history, 28, 6)));
+}
+
+#[test]
+fn event_log_shows_newest_entries() {
+    let mut state = quiet_game();
+    let mut history = EventHistory::default();
+    for wave in 1..=EVENT_HISTORY_LENGTH as u32 + 5 {
+        state.events.push(GameEvent::WaveStarted { wave });
+        history.observe(&state);
+        state.events.clear();
+    }
+    assert_eq!(history.entries().len(), EVENT_HISTORY_LENGTH);
+    let buffer = event_log(&history, 28, 3);
+    assert_eq!(screen::find(&buffer, "Wave 55 started"), Some((0, 2)));
+    assert_eq!(screen::find(&buffer, "Wave 53 started"), Some((0, 0)));
+}
+
+#[test]
+fn event_log_records_each_event_once() {
+    let mut state = quiet_game();
+    let mut history = EventHistory::default();
+    towers::build(&mut state, TowerKind::Arrow, GRASS);
+    history.observe(&state);
+    history.observe(&state);
+    assert_eq!(history.entries().len(), 1);
+}
+
+#[test]
+fn leaked_enemy_logged() {
+    let mut state = quiet_game();
+    let end = state.map.path.len() as u32 * PATH_UNITS_PER_TILE - 1;
+    enemies::spawn(&mut state, EnemyKind::Runner, end);
+    enemies::advance(&mut state);
+    let mut history = EventHistory::default();
+    history.observe(&state);
+    assert!(screen::find(&event_log(&history, 28, 3), "Runner escaped -1♥").is_some());
+}
+
+#[test]
+fn tower_details() {
+    let tower = Tower { upgrades: vec![0, 2], kills: 4, ..Tower::new(TowerKind::Arrow, GRASS) };
+    let mut harness = TestHarness::new(28, 16);
+    harness.render(|| TowerDetails { tower: Some(&tower) });
+    assert!(harness.displays("A Arrow  Lv 3"));
+    assert!(harness.displays("Target   First"));
+    assert!(harness.displays("Sharp Arrows"));
+    assert!(harness.displays("Sniper"));
+    assert!(!harness.displays("Multishot"));
+}
+
+#[test]
+fn tower_details_without_selection() {
+    let mut harness = TestHarness::new(28, 4);
+    harness.render(|| TowerDetails { tower: None });
+    assert!(harness.displays("Select a tower"));
+}
+
+#[test]
+fn minimap() {
+    let mut state = quiet_game();
+    state.towers = vec![Tower::new(TowerKind::Arrow, GRASS)];
+    enemies::spawn(&mut state, EnemyKind::Grunt, 10 * PATH_UNITS_PER_TILE);
+    let enemy = state.map.path[10];
+    // One pixel per tile, two rows of tiles per cell
+    let mut buffer = Buffer::empty(Rect::new(0, 0, MAP_WIDTH, 11));
+    Minimap { state: &state }.render(buffer.area, &mut buffer);
+
+    assert_eq!(pixel(&buffer, GRASS), colors::white());
+    assert_eq!(pixel(&buffer, enemy), colors::red());
+    assert_eq!(pixel(&buffer, state.map.path[20]), colors::brown());
+}
+
+/// Color of the top or bottom half of the cell showing `tile` at full scale.
+fn pixel(buffer: &Buffer, tile: TilePosition) -> Color {
+    let cell = buffer.get(tile.x, tile.y / 2);
+    if tile.y.is_multiple_of(2) {
+        cell.fg
+    } else {
+        cell.bg
+    }
+}
+
+#[test]
+fn minimap_scales_down() {
+    let state = quiet_game();
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 28, 10));
+    Minimap { state: &state }.render(buffer.area, &mut buffer);
+    assert_eq!(buffer.get(0, 0).symbol(), "▀");
+    assert_eq!(buffer.get(27, 0).symbol(), "▀");
+    assert_eq!(buffer.get(0, 6).symbol(), " ", "map keeps its proportions");
+}
@@ -0,0 +1,85 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::game_state::GameState;
+use data::core::interface_state::Tool;
+use data::core::map::TilePosition;
+use data::core::settings::KeyBindings;
+use data::core::tower::TowerKind;
+use display::game::status_line::StatusLine;
+use ratatui::prelude::*;
+use rules::core::{new_game, towers};
+use testing::screen;
+
+const GRASS: TilePosition = TilePosition::new(4, 4);
+
+/// Renders a [StatusLine] with default key bindings into a new buffer.
+fn status_line(state: &GameState, tool: Tool, hovered_tile: Option<TilePosition>) -> Buffer {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 80, 1));
+    StatusLine {
+        state,
+        tool,
+        selected_tower: None,
+        hovered_tile,
+        key_bindings: KeyBindings::default(),
+    }
+    .render(buffer.area, &mut buffer);
+    buffer
+}
+
+#[test]
+fn lists_hotkeys() {
+    let state = new_game::create(Default::default());
+    let buffer = status_line(&state, Tool::Select, None);
+    assert!(screen::find(&buffer, "Pick a tower from the shop").is_some());
+    assert!(screen::find(&buffer, "[Esc] Menu [p] Pause [f] Speed [q] Quit").is_some());
+
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 80, 1));
+    let key_bindings = KeyBindings { pause: KeyCode::Char('x'), ..KeyBindings::default() };
+    StatusLine {
+        state: &state,
+        tool: Tool::Select,
+        selected_tower: None,
+        hovered_tile: None,
+        key_bindings,
+    }
+    .render(buffer.area, &mut buffer);
+    assert!(screen::find(&buffer, "[x] Pause").is_some());
+}
+
+#[test]
+fn describes_building() {
+    let mut state = new_game::create(Default::default());
+    let build = Tool::Build(TowerKind::Arrow);
+    let buffer = status_line(&state, build, Some(GRASS));
+    assert!(screen::find(&buffer, "Click a tile to build Arrow for 50g").is_some());
+    assert!(screen::find(&buffer, "[Esc] Cancel").is_some());
+
+    let path = state.map.path[0];
+    let buffer = status_line(&state, build, Some(path));
+    assert!(screen::find(&buffer, "Cannot build here").is_some());
+
+    state.gold = 0;
+    let buffer = status_line(&state, build, Some(GRASS));
+    assert!(screen::find(&buffer, "Not enough gold for Arrow").is_some());
+}
+
+#[test]
+fn describes_hovered_tower() {
+    let mut state = new_game::create(Default::default());
+    towers::build(&mut state, TowerKind::Arrow, GRASS);
+    let buffer = status_line(&state, Tool::Select, Some(GRASS));
+    assert!(screen::find(&buffer, "Click to inspect Arrow").is_some());
+}
@@ -0,0 +1,211 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use data::core::actions::InterfaceAction;
+use data::core::widget_id::{WidgetId, WidgetName};
+use display::core::button::Button;
+use display::core::render_context::RenderContext;
+use display::core::text_input::{TextInput, TextInputState};
+use ratatui::prelude::*;
+use testing::events;
+use testing::harness::TestHarness;
+
+const ID: WidgetId = WidgetId::new(WidgetName::SettingsMenu);
+
+fn input() -> TextInput {
+    TextInput::new()
+        .id(ID)
+        .placeholder("Save name")
+        .on_submit(InterfaceAction::CloseModal)
+        .focused(true)
+        .build()
+}
+
+fn type_text(harness: &mut TestHarness, text: &str) {
+    for c in text.chars() {
+        harness.send(events::key_press(KeyCode::Char(c)));
+    }
+    harness.render(input);
+}
+
+fn press(harness: &mut TestHarness, code: KeyCode, modifiers: KeyModifiers) {
+    harness.send(events::key_press_with(code, modifiers));
+    harness.render(input);
+}
+
+fn text(harness: &mut TestHarness) -> String {
+    harness.context().text_input(ID).to_string()
+}
+
+#[test]
+fn placeholder() {
+    let mut harness = TestHarness::new(12, 1);
+    harness.render(|| TextInput::new().id(ID).placeholder("Save name").build());
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn typing() {
+    let mut harness = TestHarness::new(12, 1);
+    type_text(&mut harness, "abc");
+    assert_eq!(text(&mut harness), "abc");
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn initial_text() {
+    let mut harness = TestHarness::new(12, 1);
+    harness.context().set_text_input(ID, TextInputState::new("Slot"));
+    type_text(&mut harness, " 1");
+    assert_eq!(text(&mut harness), "Slot 1");
+}
+
+#[test]
+fn cursor_movement() {
+    let mut harness = TestHarness::new(20, 1);
+    type_text(&mut harness, "hello world");
+    press(&mut harness, KeyCode::Home, KeyModifiers::NONE);
+    type_text(&mut harness, ">");
+    press(&mut harness, KeyCode::Right, KeyModifiers::CONTROL);
+    type_text(&mut harness, ",");
+    press(&mut harness, KeyCode::End, KeyModifiers::NONE);
+    press(&mut harness, KeyCode::Left, KeyModifiers::NONE);
+    type_text(&mut harness, "_");
+    assert_eq!(text(&mut harness), ">hello, worl_d");
+}
+
+#[test]
+fn delete_words() {
+    let mut harness = TestHarness::new(20, 1);
+    type_text(&mut harness, "one two three");
+    press(&mut harness, KeyCode::Backspace, KeyModifiers::CONTROL);
+    assert_eq!(text(&mut harness), "one two ");
+    press(&mut harness, KeyCode::Left, KeyModifiers::CONTROL);
+    press(&mut harness, KeyCode::Left, KeyModifiers::CONTROL);
+    press(&mut harness, KeyCode::Delete, KeyModifiers::CONTROL);
+    assert_eq!(text(&mut harness), " two ");
+    press(&mut harness, KeyCode::Delete, KeyModifiers::NONE);
+    assert_eq!(text(&mut harness), "two ");
+}
+
+#[test]
+fn selection() {
+    let mut harness = TestHarness::new(20, 1);
+    type_text(&mut harness, "hello");
+    press(&mut harness, KeyCode::Left, KeyModifiers::SHIFT);
+    press(&mut harness, KeyCode::Left, KeyModifiers::SHIFT);
+    insta::assert_snapshot!(harness.snapshot());
+    type_text(&mut harness, "p!");
+    assert_eq!(text(&mut harness), "help!");
+
+    press(&mut harness, KeyCode::Char('a'), KeyModifiers::CONTROL);
+    press(&mut harness, KeyCode::Backspace, KeyModifiers::NONE);
+    assert_eq!(text(&mut harness), "");
+}
+
+#[test]
+fn wide_characters() {
+    let mut harness = TestHarness::new(12, 1);
+    type_text(&mut harness, "日本語");
+    press(&mut harness, KeyCode::Left, KeyModifiers::NONE);
+    press(&mut harness, KeyCode::Backspace, KeyModifiers::NONE);
+    assert_eq!(text(&mut harness), "日語");
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn scrolls_to_cursor() {
+    let mut harness = TestHarness::new(5, 1);
+    type_text(&mut harness, "abcdefgh");
+    assert!(harness.displays("efgh"));
+    assert!(!harness.displays("d"));
+    press(&mut harness, KeyCode::Home, KeyModifiers::NONE);
+    assert!(harness.displays("abcde"));
+}
+
+#[test]
+fn paste() {
+    let mut harness = TestHarness::new(12, 1);
+    harness.send(events::paste("foo\nbar"));
+    harness.render(input);
+    assert_eq!(text(&mut harness), "foobar");
+}
+
+#[test]
+fn validation() {
+    let mut harness = TestHarness::new(12, 1);
+    let seed = || {
+        TextInput::new()
+            .id(ID)
+            .validate(|s: &str| s.len() <= 4 && s.chars().all(|c| c.is_ascii_digit()))
+            .focused(true)
+            .build()
+    };
+    for c in "1a2345".chars() {
+        harness.send(events::key_press(KeyCode::Char(c)));
+    }
+    harness.send(events::paste("x"));
+    harness.render(seed);
+    assert_eq!(text(&mut harness), "1234");
+}
+
+#[test]
+fn submit() {
+    let mut harness = TestHarness::new(12, 1);
+    assert_eq!(harness.key(KeyCode::Enter, input), vec![InterfaceAction::CloseModal]);
+}
+
+#[test]
+fn unfocused_ignores_input() {
+    let mut harness = TestHarness::new(12, 1);
+    harness.send(events::key_press(KeyCode::Char('a')));
+    harness.send(events::paste("b"));
+    harness.render(|| TextInput::new().id(ID).build());
+    assert_eq!(text(&mut harness), "");
+}
+
+/// Focused text field next to a button using a letter as its hotkey.
+struct InputWithButton;
+
+impl StatefulWidget for InputWithButton {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        Button::new()
+            .label("Quit")
+            .action(InterfaceAction::Exit)
+            .id(WidgetId::new(WidgetName::Quit))
+            .hotkey(KeyCode::Char('q'))
+            .build()
+            .render(Rect::new(area.x, area.y + 1, area.width, 3), buf, context);
+        input().render(Rect::new(area.x, area.y, area.width, 1), buf, context);
+    }
+}
+
+#[test]
+fn focused_captures_keys() {
+    let mut harness = TestHarness::new(12, 4);
+    harness.render(|| InputWithButton);
+    assert!(harness.key(KeyCode::Char('q'), || InputWithButton).is_empty());
+    assert_eq!(text(&mut harness), "q");
+}
+
+#[test]
+fn zero_width() {
+    let mut harness = TestHarness::new(12, 1).with_widget_area(Rect::new(0, 0, 0, 1));
+    harness.render(input);
+    type_text(&mut harness, "abc");
+    assert_eq!(text(&mut harness), "abc");
+}
@@ -0,0 +1,80 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use data::core::colors;
+use data::core::widget_id::{WidgetId, WidgetName};
+use display::core::render_context::RenderContext;
+use display::core::tooltip::TOOLTIP_DELAY;
+use ratatui::prelude::*;
+use testing::harness::TestHarness;
+
+/// Widget which fills its area and displays a two-line tooltip.
+struct TowerInfo;
+
+impl StatefulWidget for TowerInfo {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, _: &mut Buffer, context: &mut RenderContext) {
+        let id = WidgetId::new(WidgetName::TowerPanel);
+        context.register(id, area);
+        context.hovered(id, area);
+        context.hover_tooltip(id, vec![
+            Line::from("Arrow Tower".bold()),
+            Line::from(vec!["Damage: ".into(), "10".fg(colors::yellow())]),
+        ]);
+    }
+}
+
+fn hovered_harness(column: u16, row: u16) -> TestHarness {
+    let mut harness = TestHarness::new(30, 10);
+    harness.hover(column, row, || TowerInfo);
+    harness
+}
+
+#[test]
+fn hidden_before_delay() {
+    let mut harness = hovered_harness(2, 2);
+    harness.advance(TOOLTIP_DELAY - Duration::from_millis(1));
+    harness.render(|| TowerInfo);
+    assert!(!harness.displays("Arrow Tower"));
+}
+
+#[test]
+fn shown_next_to_cursor() {
+    let mut harness = hovered_harness(2, 2);
+    harness.advance(TOOLTIP_DELAY);
+    harness.render(|| TowerInfo);
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn kept_inside_terminal() {
+    let mut harness = hovered_harness(28, 9);
+    harness.advance(TOOLTIP_DELAY);
+    harness.render(|| TowerInfo);
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn requests_redraw_when_delay_expires() {
+    let mut harness = hovered_harness(2, 2);
+    let hovered_at = harness.context().now();
+    assert_eq!(harness.context().redraw_at(), Some(hovered_at + TOOLTIP_DELAY));
+    harness.advance(TOOLTIP_DELAY);
+    harness.render(|| TowerInfo);
+    assert!(harness.context().redraw_at().is_none());
+    assert!(harness.displays("Arrow Tower"));
+}
@@ -0,0 +1,132 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use data::core::actions::{GameAction, InterfaceAction};
+use data::core::game_state::GameState;
+use data::core::interface_state::{Confirmation, Modal};
+use data::core::map::TilePosition;
+use data::core::tower::{TargetingMode, Tower, TowerKind};
+use display::game::tower_panel::TowerPanel;
+use rules::core::new_game;
+use testing::harness::TestHarness;
+use testing::screen;
+
+const POSITION: TilePosition = TilePosition::new(4, 4);
+
+fn arrow(upgrades: Vec<usize>) -> Tower {
+    Tower { upgrades, kills: 3, damage_dealt: 42, ..Tower::new(TowerKind::Arrow, POSITION) }
+}
+
+fn panel<'a>(state: &'a GameState, tower: &'a Tower) -> TowerPanel<'a> {
+    TowerPanel { state, tower }
+}
+
+#[test]
+fn tower_details() {
+    let state = new_game::create(Default::default());
+    let tower = arrow(vec![0]);
+    let mut harness = TestHarness::new(20, 22);
+    harness.render(|| panel(&state, &tower));
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn upgrade() {
+    let state = new_game::create(Default::default());
+    let tower = arrow(vec![]);
+    let mut harness = TestHarness::new(20, 22);
+    assert_eq!(harness.key(KeyCode::Char('u'), || panel(&state, &tower)), vec![
+        GameAction::UpgradeTower(POSITION, 0).into()
+    ]);
+    let (x, y) = screen::find(harness.buffer(), "Sharp Arrows").unwrap();
+    assert_eq!(harness.click(x, y, || panel(&state, &tower)), vec![GameAction::UpgradeTower(
+        POSITION, 0
+    )
+    .into()]);
+    assert!(harness.displays("Dmg 5→8"));
+}
+
+#[test]
+fn choose_upgrade_branch() {
+    let mut state = new_game::create(Default::default());
+    state.gold = 1000;
+    let tower = arrow(vec![0]);
+    let mut harness = TestHarness::new(20, 22);
+    harness.render(|| panel(&state, &tower));
+    assert!(harness.displays("Multishot"));
+    assert!(harness.displays("Sniper"));
+    assert_eq!(harness.key(KeyCode::Char('u'), || panel(&state, &tower)), vec![
+        GameAction::UpgradeTower(POSITION, 1).into()
+    ]);
+    assert_eq!(harness.key(KeyCode::Char('i'), || panel(&state, &tower)), vec![
+        GameAction::UpgradeTower(POSITION, 2).into()
+    ]);
+}
+
+#[test]
+fn upgrade_unaffordable() {
+    let mut state = new_game::create(Default::default());
+    state.gold = 0;
+    let tower = arrow(vec![]);
+    let mut harness = TestHarness::new(20, 22);
+    assert!(harness.key(KeyCode::Char('u'), || panel(&state, &tower)).is_empty());
+}
+
+#[test]
+fn fully_upgraded() {
+    let state = new_game::create(Default::default());
+    let tower = arrow(vec![0, 1, 3]);
+    let mut harness = TestHarness::new(20, 22);
+    harness.render(|| panel(&state, &tower));
+    assert!(harness.displays("Fully upgraded"));
+    assert!(!harness.displays("Arrow Storm"));
+}
+
+#[test]
+fn sell() {
+    let state = new_game::create(Default::default());
+    let tower = arrow(vec![]);
+    let mut harness = TestHarness::new(20, 22);
+    assert_eq!(harness.key(KeyCode::Char('s'), || panel(&state, &tower)), vec![
+        InterfaceAction::ShowModal(Modal::Confirm(Confirmation::SellTower(POSITION)))
+    ]);
+    assert!(harness.displays("+37g"));
+}
+
+#[test]
+fn change_targeting() {
+    let state = new_game::create(Default::default());
+    let tower = arrow(vec![]);
+    let mut harness = TestHarness::new(20, 22);
+    harness.render(|| panel(&state, &tower));
+    let (x, y) = screen::find(harness.buffer(), "Strongest").unwrap();
+    assert_eq!(harness.click(x, y, || panel(&state, &tower)), vec![GameAction::SetTargeting(
+        POSITION,
+        TargetingMode::Strongest
+    )
+    .into()]);
+}
+
+#[test]
+fn close() {
+    let state = new_game::create(Default::default());
+    let tower = arrow(vec![]);
+    let mut harness = TestHarness::new(20, 22);
+    harness.render(|| panel(&state, &tower));
+    let (x, y) = screen::find(harness.buffer(), "Close").unwrap();
+    assert_eq!(harness.click(x, y, || panel(&state, &tower)), vec![InterfaceAction::SelectTower(
+        None
+    )]);
+}
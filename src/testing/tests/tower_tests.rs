@@ -0,0 +1,249 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::actions::GameAction;
+use data::core::enemy::{EnemyKind, ARMOR};
+use data::core::game_state::GameState;
+use data::core::map::{TilePosition, PATH_UNITS_PER_TILE};
+use data::core::projectile::EffectKind;
+use data::core::tower::{TargetingMode, Tower, TowerKind};
+use rules::core::handle_action::handle_game_action;
+use rules::core::new_game::{self, STARTING_GOLD};
+use rules::core::{effects, enemies, towers};
+
+const GRASS: TilePosition = TilePosition::new(4, 4);
+
+#[test]
+fn build_tower() {
+    let mut state = new_game::create(Default::default());
+    handle_game_action(&mut state, GameAction::BuildTower(TowerKind::Cannon, GRASS));
+    assert_eq!(state.towers, vec![Tower::new(TowerKind::Cannon, GRASS)]);
+    assert_eq!(state.gold, STARTING_GOLD - TowerKind::Cannon.definition().cost);
+}
+
+#[test]
+fn cannot_build_without_gold() {
+    let mut state = new_game::create(Default::default());
+    state.gold = 10;
+    handle_game_action(&mut state, GameAction::BuildTower(TowerKind::Arrow, GRASS));
+    assert!(state.towers.is_empty());
+    assert_eq!(state.gold, 10);
+}
+
+#[test]
+fn cannot_build_locked_tower() {
+    let mut state = new_game::create(Default::default());
+    state.unlocked_towers = vec![TowerKind::Arrow];
+    handle_game_action(&mut state, GameAction::BuildTower(TowerKind::Frost, GRASS));
+    assert!(state.towers.is_empty());
+}
+
+#[test]
+fn buildable_tiles() {
+    let mut state = new_game::create(Default::default());
+    assert!(towers::can_build(&state, GRASS));
+    assert!(!towers::can_build(&state, state.map.path[0]));
+    assert!(!towers::can_build(&state, TilePosition::new(40, 0)), "forest");
+    assert!(!towers::can_build(&state, TilePosition::new(100, 100)));
+    towers::build(&mut state, TowerKind::Arrow, GRASS);
+    assert!(!towers::can_build(&state, GRASS));
+}
+
+#[test]
+fn upgrade_tower() {
+    let mut state = new_game::create(Default::default());
+    towers::build(&mut state, TowerKind::Arrow, GRASS);
+    let base = state.towers[0].stats();
+    handle_game_action(&mut state, GameAction::UpgradeTower(GRASS, 0));
+    let upgrade = &TowerKind::Arrow.definition().upgrades.nodes[0];
+    assert_eq!(state.towers[0].upgrades, vec![0]);
+    assert_eq!(state.towers[0].level(), 2);
+    assert_eq!(state.towers[0].stats(), base.apply(upgrade.modifiers));
+    assert_eq!(state.towers[0].invested, TowerKind::Arrow.definition().cost + upgrade.cost);
+    assert_eq!(state.gold, STARTING_GOLD - state.towers[0].invested);
+}
+
+#[test]
+fn upgrade_must_follow_tree() {
+    let mut state = new_game::create(Default::default());
+    state.gold = 10_000;
+    towers::build(&mut state, TowerKind::Arrow, GRASS);
+    handle_game_action(&mut state, GameAction::UpgradeTower(GRASS, 3));
+    assert!(state.towers[0].upgrades.is_empty());
+    handle_game_action(&mut state, GameAction::UpgradeTower(GRASS, 0));
+    handle_game_action(&mut state, GameAction::UpgradeTower(GRASS, 0));
+    assert_eq!(state.towers[0].upgrades, vec![0]);
+}
+
+#[test]
+fn choosing_branch_locks_out_other_branch() {
+    let mut state = new_game::create(Default::default());
+    state.gold = 10_000;
+    towers::build(&mut state, TowerKind::Arrow, GRASS);
+    handle_game_action(&mut state, GameAction::UpgradeTower(GRASS, 0));
+    assert_eq!(state.towers[0].available_upgrades(), &[1, 2]);
+    handle_game_action(&mut state, GameAction::UpgradeTower(GRASS, 1));
+    handle_game_action(&mut state, GameAction::UpgradeTower(GRASS, 2));
+    handle_game_action(&mut state, GameAction::UpgradeTower(GRASS, 4));
+    assert_eq!(state.towers[0].upgrades, vec![0, 1]);
+    assert_eq!(state.towers[0].available_upgrades(), &[3]);
+}
+
+#[test]
+fn cannot_upgrade_past_end_of_branch() {
+    let mut state = new_game::create(Default::default());
+    state.gold = 10_000;
+    towers::build(&mut state, TowerKind::Cannon, GRASS);
+    for upgrade in [0, 2, 1, 2] {
+        handle_game_action(&mut state, GameAction::UpgradeTower(GRASS, upgrade));
+    }
+    let definition = TowerKind::Cannon.definition();
+    assert_eq!(state.towers[0].upgrades, vec![0, 2]);
+    assert!(state.towers[0].available_upgrades().is_empty());
+    assert_eq!(
+        state.towers[0].stats(),
+        definition
+            .stats
+            .apply(definition.upgrades.nodes[0].modifiers)
+            .apply(definition.upgrades.nodes[2].modifiers)
+    );
+}
+
+#[test]
+fn sell_tower() {
+    let mut state = new_game::create(Default::default());
+    towers::build(&mut state, TowerKind::Arrow, GRASS);
+    let gold = state.gold;
+    handle_game_action(&mut state, GameAction::SellTower(GRASS));
+    assert!(state.towers.is_empty());
+    assert_eq!(state.gold, gold + TowerKind::Arrow.definition().cost * 3 / 4);
+}
+
+#[test]
+fn set_targeting() {
+    let mut state = new_game::create(Default::default());
+    towers::build(&mut state, TowerKind::Arrow, GRASS);
+    handle_game_action(&mut state, GameAction::SetTargeting(GRASS, TargetingMode::Weakest));
+    assert_eq!(state.towers[0].targeting, TargetingMode::Weakest);
+}
+
+/// Distances along the path which are within range of an Arrow tower at
+/// [GRASS].
+const NEAR: u32 = 2 * PATH_UNITS_PER_TILE;
+const FAR: u32 = 6 * PATH_UNITS_PER_TILE;
+
+/// Creates a game with an Arrow tower beside the start of the path and
+/// enemies of the given kinds at the given distances along it.
+fn skirmish(enemies: &[(EnemyKind, u32)]) -> GameState {
+    let mut state = new_game::create(Default::default());
+    state.next_wave_tick = None;
+    state.towers = vec![Tower::new(TowerKind::Arrow, GRASS)];
+    for &(kind, distance) in enemies {
+        enemies::spawn(&mut state, kind, distance);
+    }
+    state
+}
+
+#[test]
+fn tower_attacks_enemy_in_range() {
+    let mut state =
+        skirmish(&[(EnemyKind::Brute, NEAR), (EnemyKind::Brute, 30 * PATH_UNITS_PER_TILE)]);
+    towers::attack(&mut state);
+    let stats = TowerKind::Arrow.definition().stats;
+    // Brutes are armored
+    let damage = stats.damage - ARMOR;
+    assert_eq!(state.enemies[0].health, 50 - damage);
+    assert_eq!(state.enemies[1].health, 50, "out of range");
+    assert_eq!(state.towers[0].damage_dealt, damage);
+
+    towers::attack(&mut state);
+    assert_eq!(state.enemies[0].health, 50 - damage, "reloading");
+    state.tick = stats.reload as u64;
+    towers::attack(&mut state);
+    assert_eq!(state.enemies[0].health, 50 - 2 * damage);
+}
+
+#[test]
+fn targeting_mode_picks_target() {
+    let mut state = skirmish(&[(EnemyKind::Brute, NEAR), (EnemyKind::Brute, FAR)]);
+    state.enemies[0].health = 40;
+    towers::attack(&mut state);
+    assert_eq!(state.enemies[1].health, 48, "first");
+
+    let mut state = skirmish(&[(EnemyKind::Brute, NEAR), (EnemyKind::Brute, FAR)]);
+    state.enemies[0].health = 40;
+    state.towers[0].targeting = TargetingMode::Strongest;
+    towers::attack(&mut state);
+    assert_eq!(state.enemies[1].health, 48);
+
+    let mut state = skirmish(&[(EnemyKind::Brute, NEAR), (EnemyKind::Brute, FAR)]);
+    state.enemies[0].health = 40;
+    state.towers[0].targeting = TargetingMode::Weakest;
+    towers::attack(&mut state);
+    assert_eq!(state.enemies[0].health, 38);
+}
+
+#[test]
+fn armor_reduces_damage() {
+    let mut state = skirmish(&[(EnemyKind::Grunt, NEAR), (EnemyKind::Brute, FAR)]);
+    let damage = TowerKind::Arrow.definition().stats.damage;
+    assert_eq!(towers::damage_taken(&state.enemies[0], damage), damage);
+    assert_eq!(towers::damage_taken(&state.enemies[1], damage), damage - ARMOR);
+    assert_eq!(towers::damage_taken(&state.enemies[1], 1), 1, "always at least 1");
+
+    state.towers[0] = Tower::new(TowerKind::Frost, GRASS);
+    state.enemies.remove(0);
+    state.enemies[0].distance = NEAR;
+    towers::attack(&mut state);
+    assert_eq!(state.enemies[0].health, 49);
+}
+
+#[test]
+fn defeating_enemy_awards_bounty() {
+    let mut state = skirmish(&[(EnemyKind::Grunt, NEAR)]);
+    state.enemies[0].health = 3;
+    towers::attack(&mut state);
+    assert!(state.enemies.is_empty());
+    assert_eq!(state.towers[0].kills, 1);
+    assert_eq!(state.towers[0].damage_dealt, 3);
+    assert_eq!(state.gold, STARTING_GOLD + EnemyKind::Grunt.definition().bounty);
+}
+
+#[test]
+fn attack_fires_projectile_and_leaves_effect() {
+    let mut state = skirmish(&[(EnemyKind::Brute, NEAR)]);
+    state.towers[0] = Tower::new(TowerKind::Cannon, GRASS);
+    towers::attack(&mut state);
+    assert_eq!(state.projectiles.len(), 1);
+    assert_eq!(state.projectiles[0].source, TowerKind::Cannon);
+    assert_eq!(state.effects.len(), 1);
+    assert_eq!(state.effects[0].kind, EffectKind::Explosion);
+    assert_eq!(Some(state.effects[0].position), state.enemies[0].position(&state.map));
+
+    effects::advance(&mut state);
+    assert!(state.projectiles.is_empty(), "projectiles last one tick");
+    assert_eq!(state.effects[0].remaining_ticks, EffectKind::Explosion.duration() - 1);
+    for _ in 1..EffectKind::Explosion.duration() {
+        effects::advance(&mut state);
+    }
+    assert!(state.effects.is_empty());
+}
+
+#[test]
+fn arrows_leave_no_effect() {
+    let mut state = skirmish(&[(EnemyKind::Grunt, NEAR)]);
+    towers::attack(&mut state);
+    assert_eq!(state.projectiles.len(), 1);
+    assert!(state.effects.is_empty());
+}
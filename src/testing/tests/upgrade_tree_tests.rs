@@ -0,0 +1,78 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::tower;
+use data::core::upgrades::{StatModifiers, UpgradeNode, UpgradeTree, UpgradeTreeError};
+
+const fn node(next: &'static [usize]) -> UpgradeNode {
+    UpgradeNode { name: "Upgrade", cost: 10, modifiers: StatModifiers::NONE, next }
+}
+
+#[test]
+fn tower_definitions_valid() {
+    assert_eq!(tower::validate_definitions(), Ok(()));
+}
+
+#[test]
+fn branching_tree_valid() {
+    const NODES: &[UpgradeNode] = &[node(&[1, 2]), node(&[3]), node(&[]), node(&[])];
+    let tree = UpgradeTree { start: &[0], nodes: NODES };
+    assert_eq!(tree.validate(), Ok(()));
+}
+
+#[test]
+fn missing_node() {
+    const NODES: &[UpgradeNode] = &[node(&[1]), node(&[5])];
+    let tree = UpgradeTree { start: &[0], nodes: NODES };
+    assert_eq!(tree.validate(), Err(UpgradeTreeError::MissingNode { from: 1, to: 5 }));
+}
+
+#[test]
+fn missing_start_node() {
+    const NODES: &[UpgradeNode] = &[node(&[])];
+    let tree = UpgradeTree { start: &[0, 3], nodes: NODES };
+    let error = tree.validate().unwrap_err();
+    assert_eq!(error, UpgradeTreeError::MissingStartNode(3));
+    assert_eq!(error.to_string(), "A new tower starts with missing upgrade 3");
+}
+
+#[test]
+fn cycle() {
+    const NODES: &[UpgradeNode] = &[node(&[1]), node(&[2]), node(&[1])];
+    let tree = UpgradeTree { start: &[0], nodes: NODES };
+    assert_eq!(tree.validate(), Err(UpgradeTreeError::Cycle(1)));
+}
+
+#[test]
+fn multiple_parents() {
+    const NODES: &[UpgradeNode] = &[node(&[1, 2]), node(&[3]), node(&[3]), node(&[])];
+    let tree = UpgradeTree { start: &[0], nodes: NODES };
+    assert_eq!(tree.validate(), Err(UpgradeTreeError::MultipleParents(3)));
+}
+
+#[test]
+fn unreachable() {
+    const NODES: &[UpgradeNode] = &[node(&[1]), node(&[]), node(&[])];
+    let tree = UpgradeTree { start: &[0], nodes: NODES };
+    assert_eq!(tree.validate(), Err(UpgradeTreeError::Unreachable(2)));
+}
+
+#[test]
+fn too_many_branches() {
+    const NODES: &[UpgradeNode] = &[node(&[1, 2, 3]), node(&[]), node(&[]), node(&[])];
+    let tree = UpgradeTree { start: &[0], nodes: NODES };
+    assert_eq!(tree.validate(), Err(UpgradeTreeError::TooManyBranches(0)));
+    let tree = UpgradeTree { start: &[1, 2, 3], nodes: NODES };
+    assert_eq!(tree.validate(), Err(UpgradeTreeError::TooManyStartingBranches));
+}
@@ -0,0 +1,158 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Instant;
+
+use data::core::enemy::{Enemy, EnemyId, EnemyKind};
+use data::core::game_event::GameEvent;
+use data::core::game_state::GameState;
+use data::core::map::{TilePosition, PATH_UNITS_PER_TILE};
+use data::core::tower::{Tower, TowerKind};
+use display::game::visual_effects::{FloatingKind, FloatingNumber, VisualEffects, FLOAT_DURATION};
+use rules::core::{new_game, towers};
+
+fn with_enemy(health: u32) -> GameState {
+    let mut state = new_game::create(Default::default());
+    state.enemies = vec![Enemy {
+        health,
+        max_health: 20,
+        ..Enemy::new(EnemyId(1), EnemyKind::Grunt, 2 * PATH_UNITS_PER_TILE)
+    }];
+    state
+}
+
+#[test]
+fn damage_number() {
+    let now = Instant::now();
+    let mut effects = VisualEffects::default();
+    let mut state = with_enemy(20);
+    effects.observe(&state, now);
+    assert!(effects.floating().is_empty());
+
+    let position = state.map.path[2];
+    state.tick += 1;
+    state.events.push(GameEvent::EnemyDamaged { position, amount: 7 });
+    effects.observe(&state, now);
+    assert_eq!(effects.floating(), &[FloatingNumber {
+        kind: FloatingKind::Damage,
+        amount: 7,
+        position,
+        start: now,
+    }]);
+    assert_eq!(effects.floating()[0].text(), "-7");
+}
+
+#[test]
+fn events_shown_once() {
+    let now = Instant::now();
+    let mut effects = VisualEffects::default();
+    let mut state = with_enemy(20);
+    let position = state.map.path[2];
+    state.events.push(GameEvent::EnemyDamaged { position, amount: 7 });
+    effects.observe(&state, now);
+    effects.observe(&state, now);
+    assert_eq!(effects.floating().len(), 1);
+
+    state.events.clear();
+    state.events.push(GameEvent::EnemyDamaged { position, amount: 3 });
+    effects.observe(&state, now);
+    assert_eq!(effects.floating().len(), 2);
+    assert_eq!(effects.floating()[1].text(), "-3");
+}
+
+#[test]
+fn killing_blow_shows_damage_and_gold() {
+    let now = Instant::now();
+    let mut effects = VisualEffects::default();
+    let mut state = new_game::create(Default::default());
+    state.enemies = vec![
+        Enemy { health: 1, ..Enemy::new(EnemyId(1), EnemyKind::Grunt, 2 * PATH_UNITS_PER_TILE) },
+        Enemy { health: 1, ..Enemy::new(EnemyId(2), EnemyKind::Brute, 3 * PATH_UNITS_PER_TILE) },
+    ];
+    state.towers = vec![Tower::new(TowerKind::Arrow, TilePosition::new(4, 4))];
+    effects.observe(&state, now);
+
+    state.tick += 1;
+    towers::attack(&mut state);
+    state.towers[0].ready_at = 0;
+    towers::attack(&mut state);
+    effects.observe(&state, now);
+    let texts = effects.floating().iter().map(|f| f.text()).collect::<Vec<_>>();
+    assert_eq!(texts, ["-1", "+10g", "-1", "+3g"]);
+    assert_eq!(effects.floating()[1].position, state.map.path[3]);
+    assert_eq!(effects.floating()[3].position, state.map.path[2]);
+}
+
+#[test]
+fn leaked_enemy_shows_no_gold() {
+    let now = Instant::now();
+    let mut effects = VisualEffects::default();
+    let mut state = with_enemy(5);
+    effects.observe(&state, now);
+
+    state.tick += 1;
+    state.enemies.clear();
+    state.lives -= 1;
+    effects.observe(&state, now);
+    assert!(effects.floating().is_empty());
+}
+
+#[test]
+fn sell_shows_gold_at_tower() {
+    let now = Instant::now();
+    let mut effects = VisualEffects::default();
+    let mut state = new_game::create(Default::default());
+    let position = TilePosition::new(4, 4);
+    state.towers = vec![Tower::new(TowerKind::Arrow, position)];
+    effects.observe(&state, now);
+
+    towers::sell(&mut state, position);
+    effects.observe(&state, now);
+    assert_eq!(effects.floating().len(), 1);
+    assert_eq!(effects.floating()[0].kind, FloatingKind::Gold);
+    assert_eq!(effects.floating()[0].position, position);
+}
+
+#[test]
+fn numbers_expire() {
+    let now = Instant::now();
+    let mut effects = VisualEffects::default();
+    let mut state = with_enemy(20);
+    effects.observe(&state, now);
+    let position = state.map.path[2];
+    state.events.push(GameEvent::EnemyDamaged { position, amount: 10 });
+    effects.observe(&state, now);
+    effects.observe(&state, now + FLOAT_DURATION / 2);
+    assert_eq!(effects.floating().len(), 1);
+    effects.observe(&state, now + FLOAT_DURATION);
+    assert!(effects.floating().is_empty());
+}
+
+#[test]
+fn new_game_shows_its_events() {
+    let now = Instant::now();
+    let mut effects = VisualEffects::default();
+    let mut state = with_enemy(20);
+    state.tick = 100;
+    let position = state.map.path[2];
+    state.events.push(GameEvent::EnemyDamaged { position, amount: 4 });
+    state.events.push(GameEvent::EnemyDamaged { position, amount: 4 });
+    effects.observe(&state, now);
+
+    let mut next = with_enemy(3);
+    next.events.push(GameEvent::EnemyDamaged { position, amount: 2 });
+    effects.observe(&next, now);
+    assert_eq!(effects.floating().len(), 3);
+    assert_eq!(effects.floating()[2].text(), "-2");
+}
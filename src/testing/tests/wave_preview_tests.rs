@@ -0,0 +1,71 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::actions::GameAction;
+use data::core::enemy::EnemyKind;
+use data::core::game_state::GameState;
+use data::core::wave::{Wave, WaveGroup};
+use display::game::wave_preview::WavePreview;
+use rules::core::new_game;
+use testing::harness::TestHarness;
+use testing::screen;
+
+fn preview(state: &GameState) -> WavePreview<'_> {
+    WavePreview { state }
+}
+
+#[test]
+fn upcoming_waves() {
+    let mut state = new_game::create(Default::default());
+    state.wave = 5;
+    let mut harness = TestHarness::new(26, 14);
+    harness.render(|| preview(&state));
+    insta::assert_snapshot!(harness.snapshot());
+}
+
+#[test]
+fn lists_traits_and_spawn_points() {
+    let mut state = new_game::create(Default::default());
+    state.waves[0] = Wave {
+        groups: vec![WaveGroup { enemy: EnemyKind::Bat, count: 3, spawn_point: 1 }, WaveGroup {
+            enemy: EnemyKind::Brute,
+            count: 12,
+            spawn_point: 0,
+        }],
+    };
+    let mut harness = TestHarness::new(26, 14);
+    harness.render(|| preview(&state));
+    assert!(harness.displays("v Bat    ×3  @2 flying"));
+    assert!(harness.displays("B Brute  ×12 @1 armored"));
+}
+
+#[test]
+fn send_early() {
+    let state = new_game::create(Default::default());
+    let mut harness = TestHarness::new(26, 14);
+    harness.render(|| preview(&state));
+    assert!(harness.displays("+30g"));
+    let (x, y) = screen::find(harness.buffer(), "Send early").unwrap();
+    assert_eq!(harness.click(x, y, || preview(&state)), vec![GameAction::SendWaveEarly.into()]);
+}
+
+#[test]
+fn no_upcoming_waves() {
+    let mut state = new_game::create(Default::default());
+    state.wave = state.total_waves();
+    state.next_wave_tick = None;
+    let mut harness = TestHarness::new(26, 14);
+    harness.render(|| preview(&state));
+    assert!(harness.displays("No upcoming waves"));
+}
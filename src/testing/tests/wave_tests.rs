@@ -0,0 +1,144 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::actions::GameAction;
+use data::core::enemy::EnemyKind;
+use data::core::map::PATH_UNITS_PER_TILE;
+use data::core::wave::{Wave, WaveGroup};
+use rules::core::handle_action::handle_game_action;
+use rules::core::new_game::{self, FIRST_WAVE_DELAY, STARTING_GOLD, TOTAL_WAVES};
+use rules::core::tick;
+use rules::core::waves::{self, SPAWN_SPACING, WAVE_INTERVAL};
+
+#[test]
+fn waves_scheduled() {
+    let state = new_game::create(Default::default());
+    assert_eq!(state.total_waves(), TOTAL_WAVES);
+    assert_eq!(state.upcoming_waves().len(), TOTAL_WAVES as usize);
+    assert_eq!(state.waves[0].groups[0].enemy, EnemyKind::Grunt);
+    let last = state.waves.last().unwrap();
+    assert!(last.groups.iter().any(|g| g.enemy == EnemyKind::Warlord));
+}
+
+#[test]
+fn wave_starts_on_schedule() {
+    let mut state = new_game::create(Default::default());
+    for _ in 0..FIRST_WAVE_DELAY - 1 {
+        tick::run(&mut state);
+    }
+    assert_eq!(state.wave, 0);
+    tick::run(&mut state);
+    assert_eq!(state.wave, 1);
+    assert_eq!(state.next_wave_tick, Some(FIRST_WAVE_DELAY + WAVE_INTERVAL));
+    assert_eq!(state.upcoming_waves().len(), TOTAL_WAVES as usize - 1);
+}
+
+#[test]
+fn wave_enemies_spawn_in_turn() {
+    let mut state = new_game::create(Default::default());
+    waves::start_next_wave(&mut state);
+    let count = state.waves[0].groups.iter().map(|g| g.count).sum::<u32>();
+    assert!(count > 0);
+    assert_eq!(state.pending_spawns.len(), count as usize);
+    assert!(state.enemies.is_empty());
+
+    tick::run(&mut state);
+    assert_eq!(state.enemies.len(), 1);
+    assert_eq!(state.enemies[0].kind, EnemyKind::Grunt);
+    for _ in 0..SPAWN_SPACING {
+        tick::run(&mut state);
+    }
+    assert_eq!(state.enemies.len(), 2);
+
+    while !state.pending_spawns.is_empty() {
+        tick::run(&mut state);
+    }
+    assert_eq!(state.next_enemy_id.0 - 1, count);
+}
+
+#[test]
+fn group_enters_at_its_spawn_point() {
+    let mut state = new_game::create(Default::default());
+    let distance = 10 * PATH_UNITS_PER_TILE;
+    state.map.spawn_points = vec![0, distance];
+    state.waves = vec![Wave {
+        groups: vec![WaveGroup { enemy: EnemyKind::Grunt, count: 1, spawn_point: 1 }, WaveGroup {
+            enemy: EnemyKind::Brute,
+            count: 1,
+            spawn_point: 0,
+        }],
+    }];
+    waves::start_next_wave(&mut state);
+    assert_eq!(state.pending_spawns[0].spawn_point, 1);
+
+    waves::spawn_pending(&mut state);
+    assert_eq!(state.enemies[0].distance, distance);
+    assert_eq!(state.enemies[0].position(&state.map), Some(state.map.path[10]));
+    state.tick += SPAWN_SPACING;
+    waves::spawn_pending(&mut state);
+    assert_eq!(state.enemies[1].distance, 0);
+}
+
+#[test]
+fn every_group_has_enemies() {
+    let state = new_game::create(Default::default());
+    assert!(state.waves.iter().flat_map(|w| &w.groups).all(|g| g.count > 0));
+}
+
+#[test]
+fn boss_spawns_in_last_wave() {
+    let mut state = new_game::create(Default::default());
+    state.wave = TOTAL_WAVES - 1;
+    waves::start_next_wave(&mut state);
+    while !state.pending_spawns.is_empty() && state.boss().is_none() {
+        tick::run(&mut state);
+    }
+    assert_eq!(state.boss().map(|b| b.kind), Some(EnemyKind::Warlord));
+}
+
+#[test]
+fn no_wave_scheduled_after_last() {
+    let mut state = new_game::create(Default::default());
+    for _ in 0..TOTAL_WAVES {
+        waves::start_next_wave(&mut state);
+    }
+    assert_eq!(state.wave, TOTAL_WAVES);
+    assert_eq!(state.next_wave_tick, None);
+    assert!(state.upcoming_waves().is_empty());
+    waves::start_next_wave(&mut state);
+    assert_eq!(state.wave, TOTAL_WAVES);
+}
+
+#[test]
+fn send_wave_early() {
+    let mut state = new_game::create(Default::default());
+    state.tick = 200;
+    assert_eq!(waves::early_bonus(&state), Some(20));
+    handle_game_action(&mut state, GameAction::SendWaveEarly);
+    assert_eq!(state.wave, 1);
+    assert_eq!(state.gold, STARTING_GOLD + 20);
+    assert_eq!(state.next_wave_tick, Some(200 + WAVE_INTERVAL));
+    assert!(!state.pending_spawns.is_empty());
+}
+
+#[test]
+fn cannot_send_early_after_last_wave() {
+    let mut state = new_game::create(Default::default());
+    state.wave = TOTAL_WAVES;
+    state.next_wave_tick = None;
+    assert_eq!(waves::early_bonus(&state), None);
+    handle_game_action(&mut state, GameAction::SendWaveEarly);
+    assert_eq!(state.wave, TOTAL_WAVES);
+    assert_eq!(state.gold, STARTING_GOLD);
+}
@@ -0,0 +1,106 @@
+// Copyright © termdef 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::core::actions::InterfaceAction;
+use data::core::widget_id::{WidgetId, WidgetName, MAX_DEPTH};
+use display::core::button::Button;
+use display::core::render_context::RenderContext;
+use ratatui::prelude::*;
+use testing::harness::TestHarness;
+
+#[test]
+fn debug_name() {
+    let id = WidgetId::new(WidgetName::SettingsMenu).child(WidgetName::KeyBinding).index(2);
+    assert_eq!(format!("{id:?}"), "SettingsMenu/KeyBinding[2]");
+    assert_eq!(id.to_string(), "SettingsMenu/KeyBinding[2]");
+
+    // Example from the WidgetId docs
+    let upgrade = WidgetId::new(WidgetName::TowerPanel).child(WidgetName::Upgrade).index(3);
+    assert_eq!(upgrade.to_string(), "TowerPanel/Upgrade[3]");
+}
+
+#[test]
+fn equality() {
+    let menu = WidgetId::new(WidgetName::PauseMenu);
+    assert_eq!(menu.child(WidgetName::Quit), menu.child(WidgetName::Quit));
+    assert_ne!(
+        menu.child(WidgetName::Quit),
+        WidgetId::new(WidgetName::MainMenu).child(WidgetName::Quit)
+    );
+    assert_ne!(menu.child(WidgetName::Quit), WidgetId::new(WidgetName::Quit));
+    assert_eq!(menu.index(7), menu.index(7));
+    assert_ne!(menu.index(7), menu.index(8));
+}
+
+#[test]
+fn beyond_maximum_depth() {
+    let mut id = WidgetId::new(WidgetName::MainMenu);
+    for i in 1..MAX_DEPTH {
+        id = id.index(i);
+    }
+    let parent = WidgetId::new(WidgetName::MainMenu).index(1);
+    assert_eq!(id.index(7), id.index(7));
+    assert_ne!(id.index(7), id.index(8));
+    assert_ne!(id.index(7), id.child(WidgetName::Back));
+    assert_ne!(id.index(7), id);
+    assert!(parent.is_ancestor_of(id.index(7)));
+}
+
+struct TwoButtons {
+    first: WidgetId,
+    second: WidgetId,
+}
+
+impl StatefulWidget for TwoButtons {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let [top, bottom] = Layout::vertical([Constraint::Length(3); 2]).areas(area);
+        for (id, area) in [(self.first, top), (self.second, bottom)] {
+            Button::new()
+                .label("Button")
+                .action(InterfaceAction::NewGame)
+                .id(id)
+                .build()
+                .render(area, buf, context);
+        }
+    }
+}
+
+#[test]
+fn distinct_ids() {
+    let list = WidgetId::new(WidgetName::MainMenu);
+    let mut harness = TestHarness::new(10, 6);
+    harness.render(|| TwoButtons { first: list.index(0), second: list.index(1) });
+    harness.render(|| TwoButtons { first: list.index(0), second: list.index(1) });
+}
+
+#[test]
+#[should_panic(expected = "Duplicate widget ID MainMenu[0]")]
+fn duplicate_ids() {
+    let list = WidgetId::new(WidgetName::MainMenu);
+    let mut harness = TestHarness::new(10, 6);
+    harness.render(|| TwoButtons { first: list.index(0), second: list.index(0) });
+}
+
+#[test]
+fn ancestors() {
+    let parent = WidgetId::new(WidgetName::SettingsMenu);
+    let child = parent.child(WidgetName::KeyBinding);
+    assert!(parent.is_ancestor_of(child));
+    assert!(parent.is_ancestor_of(child.index(2)));
+    assert!(!parent.is_ancestor_of(parent));
+    assert!(!child.is_ancestor_of(parent));
+    assert!(!WidgetId::new(WidgetName::PauseMenu).is_ancestor_of(child));
+}